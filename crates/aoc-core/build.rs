@@ -0,0 +1,33 @@
+use std::{env, fs, io, path::Path};
+
+// Generates YEARS from the `aoc-yYYYY` crates next to this one, so a year is
+// valid exactly when it has a crate for its solvers.
+fn main() -> io::Result<()> {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let crates_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    println!("cargo:rerun-if-changed={}", crates_dir.display());
+
+    let mut years: Vec<u16> = fs::read_dir(&crates_dir)?
+        .filter_map(|entry| year_crate(&entry.ok()?.path()))
+        .collect();
+    years.sort();
+
+    let years: Vec<String> = years.iter().map(u16::to_string).collect();
+    let contents = format!("const YEARS: &[u16] = &[{}];\n", years.join(", "));
+    fs::write(Path::new(&out_dir).join("years.rs"), contents)
+}
+
+// `crates/aoc-y2023` -> `2023`
+fn year_crate(path: &Path) -> Option<u16> {
+    if !path.is_dir() {
+        return None;
+    }
+    let digits = path.file_name()?.to_str()?.strip_prefix("aoc-y")?;
+    if digits.len() != 4 {
+        return None;
+    }
+    digits.parse().ok()
+}
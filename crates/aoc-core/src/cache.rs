@@ -0,0 +1,159 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::core::{Answer, CoreError, Day, Part, Result, Year};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct CachedAnswer {
+    pub answer: Answer,
+    pub elapsed_us: u64,
+}
+
+impl CachedAnswer {
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_micros(self.elapsed_us)
+    }
+}
+
+// Answers already computed for a particular input by a particular build of
+// the binary, so batch commands can skip solvers that can't have changed.
+#[derive(Debug, Default)]
+pub struct AnswerCache {
+    path: PathBuf,
+    entries: HashMap<String, CachedAnswer>,
+}
+
+impl AnswerCache {
+    pub fn load(path: &Path) -> Result<Self> {
+        let entries = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_default(),
+            Err(_) => HashMap::new(),
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            entries,
+        })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&CachedAnswer> {
+        self.entries.get(key)
+    }
+
+    pub fn insert(&mut self, key: String, answer: &Answer, elapsed: Duration) {
+        let cached = CachedAnswer {
+            answer: answer.clone(),
+            elapsed_us: elapsed.as_micros() as u64,
+        };
+        self.entries.insert(key, cached);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents =
+            serde_json::to_string(&self.entries).map_err(|e| CoreError::General(e.to_string()))?;
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+}
+
+pub fn default_path() -> Result<PathBuf> {
+    Ok(cache_dir()?.join("answers.json"))
+}
+
+pub fn cache_dir() -> Result<PathBuf> {
+    let dir = match env::var("XDG_CACHE_HOME") {
+        Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = env::var("HOME").map_err(|_| CoreError::general("HOME is not set"))?;
+            PathBuf::from(home).join(".cache")
+        }
+    };
+    Ok(dir.join("aoc"))
+}
+
+pub fn key(year: &Year, day: &Day, part: &Part, input: &[u8], binary: &str) -> String {
+    format!(
+        "{}-{}-{}-{}-{}",
+        year,
+        day,
+        part.raw_value(),
+        sha256_hex(input),
+        binary
+    )
+}
+
+// Identifies this build of the binary by its size and modification time;
+// rebuilding after changing a solver invalidates everything it cached.
+pub fn binary_fingerprint() -> String {
+    let metadata = env::current_exe().and_then(fs::metadata);
+    match metadata {
+        Ok(metadata) => {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_secs());
+            format!("{}.{}", metadata.len(), modified)
+        }
+        Err(_) => String::new(),
+    }
+}
+
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keys_depend_on_input_and_binary() {
+        let year: Year = "2023".parse().unwrap();
+        let day: Day = "7".parse().unwrap();
+        let part: Part = "1".parse().unwrap();
+
+        let a = key(&year, &day, &part, b"32T3K 765", "1.2");
+        assert_eq!(a, key(&year, &day, &part, b"32T3K 765", "1.2"));
+        assert_ne!(a, key(&year, &day, &part, b"32T3K 766", "1.2"));
+        assert_ne!(a, key(&year, &day, &part, b"32T3K 765", "1.3"));
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn can_round_trip_through_disk() {
+        let path = env::temp_dir().join(format!("aoc-cache-{}.json", std::process::id()));
+        let mut cache = AnswerCache::load(&path).unwrap();
+        assert_eq!(cache.get("k"), None);
+
+        cache.insert(
+            "k".to_string(),
+            &6440u64.into(),
+            Duration::from_micros(1500),
+        );
+        cache.save().unwrap();
+
+        let cache = AnswerCache::load(&path).unwrap();
+        assert_eq!(cache.get("k").unwrap().answer, "6440");
+        assert_eq!(
+            cache.get("k").unwrap().elapsed(),
+            Duration::from_micros(1500)
+        );
+        fs::remove_file(&path).unwrap();
+    }
+}
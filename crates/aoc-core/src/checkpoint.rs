@@ -0,0 +1,163 @@
+use std::{
+    fs,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    cache,
+    core::{CoreError, Day, Part, Result, Year},
+};
+
+const SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+// Where a long-running solver can save how far it's got. Checkpoints are
+// tagged with a hash of the input they came from, so one is never resumed
+// against a different input. The default does nothing.
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoints(Option<Arc<Store>>);
+
+#[derive(Debug)]
+struct Store {
+    path: PathBuf,
+    input: String,
+    resume: bool,
+    interval: Duration,
+    last_saved: Mutex<Instant>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Checkpoint<T> {
+    input: String,
+    state: T,
+}
+
+impl Checkpoints {
+    pub fn new(path: PathBuf, input: &[u8], resume: bool) -> Self {
+        Self::with_interval(path, input, resume, SAVE_INTERVAL)
+    }
+
+    pub fn with_interval(path: PathBuf, input: &[u8], resume: bool, interval: Duration) -> Self {
+        Self(Some(Arc::new(Store {
+            path,
+            input: cache::sha256_hex(input),
+            resume,
+            interval,
+            last_saved: Mutex::new(Instant::now()),
+        })))
+    }
+
+    // Only resumed if asked to, and only if the checkpoint is for this input.
+    pub fn load<T: DeserializeOwned>(&self) -> Option<T> {
+        let store = self.0.as_ref().filter(|store| store.resume)?;
+        let contents = fs::read_to_string(&store.path).ok()?;
+        let checkpoint: Checkpoint<T> = serde_json::from_str(&contents).ok()?;
+        if checkpoint.input != store.input {
+            return None;
+        }
+        tracing::info!(path = %store.path.display(), "resuming from checkpoint");
+        Some(checkpoint.state)
+    }
+
+    // Cheap to call often: it only writes once per interval.
+    pub fn save<T: Serialize>(&self, state: &T) -> Result<()> {
+        let Some(store) = &self.0 else {
+            return Ok(());
+        };
+        if store.last_saved.lock().unwrap().elapsed() < store.interval {
+            return Ok(());
+        }
+        self.save_now(state)
+    }
+
+    // Saves whatever the interval, for when the solver is about to stop.
+    pub fn save_now<T: Serialize>(&self, state: &T) -> Result<()> {
+        let Some(store) = &self.0 else {
+            return Ok(());
+        };
+        let mut last_saved = store.last_saved.lock().unwrap();
+
+        let checkpoint = Checkpoint {
+            input: store.input.clone(),
+            state,
+        };
+        let contents =
+            serde_json::to_string(&checkpoint).map_err(|e| CoreError::General(e.to_string()))?;
+        if let Some(dir) = store.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&store.path, contents)?;
+        *last_saved = Instant::now();
+        Ok(())
+    }
+
+    // Once solved there's nothing left to resume.
+    pub fn clear(&self) -> Result<()> {
+        match &self.0 {
+            Some(store) if store.path.exists() => Ok(fs::remove_file(&store.path)?),
+            _ => Ok(()),
+        }
+    }
+}
+
+// Each algorithm gets its own checkpoint, so finishing with one doesn't
+// throw away another's progress.
+pub fn default_path(year: &Year, day: &Day, part: &Part, algo: Option<&str>) -> Result<PathBuf> {
+    let filename = match algo {
+        Some(algo) => format!("{}{}-{}-{}.json", year, day, part.raw_value(), algo),
+        None => format!("{}{}-{}.json", year, day, part.raw_value()),
+    };
+    Ok(cache::cache_dir()?.join("checkpoints").join(filename))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn checkpoints_are_resumed_for_the_same_input() -> Result<()> {
+        let path = env::temp_dir().join(format!("aoc-checkpoint-{}.json", std::process::id()));
+        let checkpoints = |input: &[u8], resume| {
+            Checkpoints::with_interval(path.clone(), input, resume, Duration::ZERO)
+        };
+
+        checkpoints(b"input", false).save(&(3, "three"))?;
+        assert_eq!(checkpoints(b"input", false).load::<(u32, String)>(), None);
+        assert_eq!(checkpoints(b"other", true).load::<(u32, String)>(), None);
+        assert_eq!(
+            checkpoints(b"input", true).load::<(u32, String)>(),
+            Some((3, "three".to_string()))
+        );
+
+        checkpoints(b"input", true).clear()?;
+        assert!(!path.exists());
+        Ok(())
+    }
+
+    #[test]
+    fn saves_are_throttled() -> Result<()> {
+        let path = env::temp_dir().join(format!("aoc-throttled-{}.json", std::process::id()));
+        let checkpoints = Checkpoints::with_interval(path.clone(), b"", true, SAVE_INTERVAL);
+        checkpoints.save(&1)?;
+        assert!(!path.exists());
+
+        checkpoints.save_now(&1)?;
+        assert!(path.exists());
+        checkpoints.clear()?;
+        Ok(())
+    }
+
+    #[test]
+    fn algorithms_have_their_own_checkpoints() -> Result<()> {
+        let (year, day, part) = ("2023".parse()?, "5".parse()?, "2".parse()?);
+        let default = default_path(&year, &day, &part, None)?;
+        let brute = default_path(&year, &day, &part, Some("brute"))?;
+        assert_ne!(default, brute);
+        assert!(brute.ends_with("checkpoints/202305-2-brute.json"));
+        Ok(())
+    }
+}
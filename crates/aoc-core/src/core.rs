@@ -0,0 +1,914 @@
+use std::any::Any;
+use std::fmt;
+use std::io;
+use std::mem;
+use std::path::PathBuf;
+use std::result;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+use std::time::{Duration, Instant};
+use std::{num::ParseIntError, ops::RangeInclusive, str::FromStr};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+use crate::checkpoint::Checkpoints;
+use crate::grid::GridError;
+use crate::string_scanner::StringScannerError;
+
+#[derive(Debug, Error)]
+pub enum CoreError {
+    #[error("IO Error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Bad number: {0}")]
+    BadNumber(#[from] ParseIntError),
+    #[error("Couldn't scan string: {0}")]
+    StringScanner(#[from] StringScannerError),
+    #[error("Bad grid: {0}")]
+    Grid(#[from] GridError),
+    #[error("HTTP Error: {0}")]
+    Http(String),
+    #[error("Bad argument: {0}")]
+    Argument(#[from] ArgumentError),
+    #[error("Input file {} is missing", .0.display())]
+    MissingInput(PathBuf),
+    #[error("Line {line}: {source}{}", snippet(.text, .source))]
+    Parse {
+        line: usize,
+        text: String,
+        source: Box<CoreError>,
+    },
+    #[error("No solver for {year} day {} part {}", day.raw_value(), part.raw_value())]
+    SolverNotImplemented { year: Year, day: Day, part: Part },
+    #[error("Interrupted after {0}")]
+    Interrupted(String),
+    #[error("General Error: {0}")]
+    General(String),
+}
+
+impl CoreError {
+    pub fn general(reason: &str) -> Self {
+        Self::General(reason.to_string())
+    }
+
+    pub fn parse(line: usize, text: &str, source: CoreError) -> Self {
+        Self::Parse {
+            line,
+            text: text.to_string(),
+            source: Box::new(source),
+        }
+    }
+
+    // Where in the offending line things went wrong, if the scanner knows.
+    pub fn column(&self) -> Option<usize> {
+        match self {
+            Self::StringScanner(e) => Some(e.position()),
+            Self::Parse { source, .. } => source.column(),
+            _ => None,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::MissingInput(_) => "missing_input",
+            Self::Parse { .. } => "parse",
+            Self::SolverNotImplemented { .. } => "not_implemented",
+            Self::Interrupted(_) => "interrupted",
+            _ => "internal",
+        }
+    }
+
+    // Distinct exit codes let scripts tell "not solved yet" apart from a
+    // genuine failure.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::MissingInput(_) => 2,
+            Self::Parse { .. } => 3,
+            Self::SolverNotImplemented { .. } => 4,
+            // The usual exit code for a process stopped by SIGINT.
+            Self::Interrupted(_) => 130,
+            _ => 1,
+        }
+    }
+}
+
+// The offending line, with a caret under the failure when its column is known.
+fn snippet(text: &str, source: &CoreError) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    match source.column() {
+        Some(column) => format!("\n    {}\n    {}^", text, " ".repeat(column)),
+        None => format!("\n    {}", text),
+    }
+}
+
+pub type Result<T> = result::Result<T, CoreError>;
+
+pub type SolverFn = fn() -> Box<dyn Solver>;
+
+pub trait Solver {
+    fn handle_line(&mut self, line: &str) -> Result<()>;
+    fn extract_solution(&mut self) -> Result<Answer>;
+
+    // Solvers whose input is awkward to take a line at a time (e.g. sections
+    // separated by blank lines) can return true here and override
+    // `handle_input`; the runner then reads the whole input up front.
+    fn whole_input(&self) -> bool {
+        false
+    }
+
+    fn handle_input(&mut self, input: &str) -> Result<()> {
+        for (i, line) in input.lines().enumerate() {
+            self.handle_line(line)
+                .map_err(|e| CoreError::parse(i + 1, line, e))?;
+        }
+        Ok(())
+    }
+
+    fn stats(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
+    // The puzzle's title, e.g. "Camel Cards", as shown by `list` and `report`.
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
+
+    // Slow solvers are started first when running several at once.
+    fn cost(&self) -> Cost {
+        Cost::Fast
+    }
+
+    // The model built from the input so far, for `--dump-parsed`.
+    fn dump(&self) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    // Long-running solvers can hang on to this and report how far they've
+    // got; the others can ignore it.
+    fn set_progress(&mut self, _progress: Progress) {}
+
+    // Solvers with long loops can check this and give up early with
+    // `CoreError::Interrupted`.
+    fn set_cancellation(&mut self, _cancellation: Cancellation) {}
+
+    // Brute-force solvers can save their state here and resume from it with
+    // `--resume`.
+    fn set_checkpoints(&mut self, _checkpoints: Checkpoints) {}
+
+    // For `--seed`; randomised solvers should seed their `Rng` with this, and
+    // use a fixed seed of their own otherwise, so runs are reproducible.
+    fn set_seed(&mut self, _seed: u64) {}
+
+    // For `--visualize`; solvers that can draw themselves return `Some(self)`.
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        None
+    }
+
+    // When both parts are run on the same input, the part 2 solver can take
+    // over what the part 1 solver parsed instead of reading the lines again.
+    // Returns whether it did; only solvers for the same puzzle can share.
+    fn take_parsed(&mut self, _other: &mut dyn Solver) -> bool {
+        false
+    }
+
+    // Lets `take_parsed` check what kind of solver it's been given.
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        None
+    }
+}
+
+pub trait Visualize {
+    // Each frame is a complete picture of the state at some point; more than
+    // one frame gets shown as an animation.
+    fn frames(&self) -> Result<Vec<String>>;
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    flag: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Cancellation {
+    // Cancels itself once `limit` has passed, for callers that can't wait
+    // for ever, like the server.
+    pub fn after(limit: Duration) -> Self {
+        Self {
+            flag: Default::default(),
+            deadline: Some(Instant::now() + limit),
+        }
+    }
+
+    pub fn cancel(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.flag.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    // For handing to something that sets the flag itself, like a signal
+    // handler.
+    pub fn flag(&self) -> Arc<AtomicBool> {
+        self.flag.clone()
+    }
+}
+
+// A callback taking (done, total). The default one does nothing, so solvers
+// can report unconditionally.
+#[derive(Clone, Default)]
+pub struct Progress(Option<Arc<dyn Fn(u64, u64) + Send + Sync>>);
+
+impl Progress {
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        Self(Some(Arc::new(callback)))
+    }
+
+    pub fn report(&self, done: u64, total: u64) {
+        if let Some(callback) = &self.0 {
+            callback(done, total);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Cost {
+    #[default]
+    Fast,
+    Slow,
+}
+
+impl fmt::Display for Cost {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Fast => write!(f, "fast"),
+            Self::Slow => write!(f, "slow"),
+        }
+    }
+}
+
+// For days where both parts work from the same parsed input. `parse_line`
+// builds it up a line at a time, so parse errors still carry line numbers.
+pub trait Puzzle: 'static {
+    type Parsed: Default + 'static;
+
+    fn parse_line(parsed: &mut Self::Parsed, line: &str) -> Result<()>;
+    fn part_1(parsed: &Self::Parsed) -> Result<Answer>;
+    fn part_2(parsed: &Self::Parsed) -> Result<Answer>;
+
+    fn title() -> Option<&'static str> {
+        None
+    }
+
+    fn description() -> Option<&'static str> {
+        None
+    }
+
+    fn cost(_part: PuzzlePart) -> Cost {
+        Cost::Fast
+    }
+
+    fn dump(_parsed: &Self::Parsed) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn solver(part: PuzzlePart) -> Box<dyn Solver>
+    where
+        Self: Sized,
+    {
+        Box::new(PuzzleSolver::<Self> {
+            parsed: Default::default(),
+            part,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PuzzlePart {
+    One,
+    Two,
+}
+
+struct PuzzleSolver<P: Puzzle> {
+    parsed: P::Parsed,
+    part: PuzzlePart,
+}
+
+impl<P: Puzzle> Solver for PuzzleSolver<P> {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        P::parse_line(&mut self.parsed, line)
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        match self.part {
+            PuzzlePart::One => P::part_1(&self.parsed),
+            PuzzlePart::Two => P::part_2(&self.parsed),
+        }
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        P::title()
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        P::description()
+    }
+
+    fn cost(&self) -> Cost {
+        P::cost(self.part)
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        P::dump(&self.parsed)
+    }
+
+    fn take_parsed(&mut self, other: &mut dyn Solver) -> bool {
+        match other.as_any_mut().and_then(|o| o.downcast_mut::<Self>()) {
+            Some(other) => {
+                self.parsed = mem::take(&mut other.parsed);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn as_any_mut(&mut self) -> Option<&mut dyn Any> {
+        Some(self)
+    }
+}
+
+// For inputs made of blank-line separated blocks. The adapter returned by
+// `solver` does the buffering and hands over one block at a time.
+pub trait BlockSolver: 'static {
+    fn handle_block(&mut self, block: &[&str]) -> Result<()>;
+    fn extract_solution(&mut self) -> Result<Answer>;
+
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        None
+    }
+
+    fn cost(&self) -> Cost {
+        Cost::Fast
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn set_progress(&mut self, _progress: Progress) {}
+
+    fn set_cancellation(&mut self, _cancellation: Cancellation) {}
+
+    fn set_checkpoints(&mut self, _checkpoints: Checkpoints) {}
+
+    fn set_seed(&mut self, _seed: u64) {}
+
+    fn solver(self) -> Box<dyn Solver>
+    where
+        Self: Sized,
+    {
+        Box::new(BlockAdapter {
+            solver: self,
+            pending: vec![],
+            start_line: 1,
+            next_line: 1,
+        })
+    }
+}
+
+struct BlockAdapter<B: BlockSolver> {
+    solver: B,
+    pending: Vec<String>,
+    start_line: usize,
+    next_line: usize,
+}
+
+impl<B: BlockSolver> BlockAdapter<B> {
+    fn flush(&mut self) -> Result<()> {
+        if !self.pending.is_empty() {
+            let block: Vec<&str> = self.pending.iter().map(String::as_str).collect();
+            // There's no telling which line of the block was at fault, so
+            // only the block's first line number is given.
+            self.solver
+                .handle_block(&block)
+                .map_err(|e| CoreError::parse(self.start_line, "", e))?;
+            self.pending.clear();
+        }
+        self.start_line = self.next_line;
+        Ok(())
+    }
+}
+
+impl<B: BlockSolver> Solver for BlockAdapter<B> {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        self.next_line += 1;
+        if line.trim().is_empty() {
+            self.flush()
+        } else {
+            self.pending.push(line.to_string());
+            Ok(())
+        }
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        self.flush()?;
+        self.solver.extract_solution()
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.solver.title()
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        self.solver.description()
+    }
+
+    fn cost(&self) -> Cost {
+        self.solver.cost()
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        self.solver.dump()
+    }
+
+    fn set_progress(&mut self, progress: Progress) {
+        self.solver.set_progress(progress);
+    }
+
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.solver.set_cancellation(cancellation);
+    }
+
+    fn set_checkpoints(&mut self, checkpoints: Checkpoints) {
+        self.solver.set_checkpoints(checkpoints);
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.solver.set_seed(seed);
+    }
+
+    fn handle_input(&mut self, input: &str) -> Result<()> {
+        for line in input.lines() {
+            self.handle_line(line)?;
+        }
+        self.flush()
+    }
+}
+
+// Numbers are kept as numbers so recorded answers can be compared without
+// caring about formatting. They're serialized as strings, since that's what
+// the puzzle takes and some answers are too big for a JSON number to hold
+// exactly; strings that are numbers come back as numbers.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Unsigned(u64),
+            Signed(i64),
+            Text(String),
+        }
+
+        Ok(match Raw::deserialize(deserializer)? {
+            Raw::Unsigned(n) => Self::Unsigned(n),
+            Raw::Signed(n) => Self::Signed(n),
+            Raw::Text(text) => Self::parse(text),
+        })
+    }
+}
+
+impl Answer {
+    // Only strings that print back exactly the same become numbers, so text
+    // answers like "007" are left alone.
+    fn parse(text: String) -> Self {
+        if let Ok(n) = text.parse::<u64>() {
+            if n.to_string() == text {
+                return Self::Unsigned(n);
+            }
+        }
+        if let Ok(n) = text.parse::<i64>() {
+            if n.to_string() == text {
+                return Self::Signed(n);
+            }
+        }
+        Self::Text(text)
+    }
+
+    // Whether `recorded` (e.g. from an answers file) is the same answer,
+    // ignoring surrounding whitespace and leading zeros or `+` signs.
+    pub fn matches(&self, recorded: &str) -> bool {
+        let recorded = recorded.trim();
+        match self {
+            Self::Unsigned(n) => recorded.parse::<i128>() == Ok(*n as i128),
+            Self::Signed(n) => recorded.parse::<i128>() == Ok(*n as i128),
+            Self::Text(text) => text.trim() == recorded,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Unsigned(n) => write!(f, "{}", n),
+            Self::Signed(n) => write!(f, "{}", n),
+            Self::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.to_string().as_str() == *other
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident as $inner:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self::$variant(value as $inner)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Unsigned as u64: u8, u16, u32, u64, usize);
+answer_from!(Signed as i64: i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SolutionReport {
+    pub year: u16,
+    pub day: u16,
+    pub part: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<&'static str>,
+    pub answer: Answer,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_millis")]
+    pub elapsed: Duration,
+}
+
+impl SolutionReport {
+    pub fn new(year: &Year, day: &Day, part: &Part, answer: Answer, elapsed: Duration) -> Self {
+        Self {
+            year: year.raw_value(),
+            day: day.raw_value(),
+            part: part.raw_value(),
+            title: None,
+            answer,
+            elapsed,
+        }
+    }
+}
+
+fn serialize_millis<S: Serializer>(
+    elapsed: &Duration,
+    serializer: S,
+) -> result::Result<S::Ok, S::Error> {
+    serializer.serialize_u128(elapsed.as_millis())
+}
+
+#[derive(Debug, Error)]
+pub enum ArgumentError {
+    #[error("Bad numeric argument")]
+    Number(#[from] ParseIntError),
+    #[error("Value {0} is not within the range {1:?}")]
+    OutOfRange(u16, RangeInclusive<u16>),
+    #[error("Year {0} is not one of {1:?}")]
+    UnknownYear(u16, &'static [u16]),
+}
+
+// Generated by build.rs from the year crates. Whether a year has any solvers
+// compiled in is up to the runner.
+include!(concat!(env!("OUT_DIR"), "/years.rs"));
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Year(u16);
+
+impl FromStr for Year {
+    type Err = ArgumentError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        Self::new(s.parse()?)
+    }
+}
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl Year {
+    pub fn new(year: u16) -> result::Result<Self, ArgumentError> {
+        match YEARS.contains(&year) {
+            true => Ok(Self(year)),
+            false => Err(ArgumentError::UnknownYear(year, YEARS)),
+        }
+    }
+
+    pub fn all() -> impl Iterator<Item = Year> {
+        YEARS.iter().map(|&year| Self(year))
+    }
+
+    pub fn raw_value(&self) -> u16 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Day(u16);
+
+impl FromStr for Day {
+    type Err = ArgumentError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let day = to_num_within_range(s, 1..=25)?;
+        Ok(Self(day))
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+impl Day {
+    pub fn raw_value(&self) -> u16 {
+        self.0
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        (1..=25).map(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Part(u16);
+
+impl FromStr for Part {
+    type Err = ArgumentError;
+
+    fn from_str(s: &str) -> result::Result<Self, Self::Err> {
+        let part = to_num_within_range(s, 1..=2)?;
+        Ok(Self(part))
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:02}", self.0)
+    }
+}
+
+impl Part {
+    pub fn raw_value(&self) -> u16 {
+        self.0
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        (1..=2).map(Self)
+    }
+}
+
+fn to_num_within_range(s: &str, range: RangeInclusive<u16>) -> result::Result<u16, ArgumentError> {
+    let raw_value = s.parse::<u16>()?;
+    let value = assert_within_range_inclusive(raw_value, &range)?;
+    Ok(value)
+}
+
+fn assert_within_range_inclusive(
+    value: u16,
+    range: &RangeInclusive<u16>,
+) -> result::Result<u16, ArgumentError> {
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ArgumentError::OutOfRange(value, range.clone()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn years_are_those_with_a_crate() {
+        let years: Vec<u16> = Year::all().map(|year| year.raw_value()).collect();
+        assert_eq!(years, vec![2015, 2019, 2022, 2023, 2024]);
+        assert_eq!("2023".parse::<Year>().unwrap(), Year(2023));
+        assert!(matches!(
+            "2016".parse::<Year>(),
+            Err(ArgumentError::UnknownYear(2016, _))
+        ));
+        assert!(matches!(
+            "20x3".parse::<Year>(),
+            Err(ArgumentError::Number(_))
+        ));
+    }
+
+    #[test]
+    fn err_if_value_not_in_range() {
+        let range: RangeInclusive<u16> = 15..=20;
+        let result = assert_within_range_inclusive(10, &range);
+
+        if let Err(ArgumentError::OutOfRange(found_value, found_range)) = result {
+            assert_eq!(found_value, 10);
+            assert_eq!(found_range, range);
+        } else {
+            panic!("{}", &format!("Expected Err but got {:?}", result));
+        }
+    }
+
+    #[test]
+    fn ok_if_value_in_range() {
+        let range: RangeInclusive<u16> = 5..=20;
+        let in_range: result::Result<u16, ArgumentError> =
+            assert_within_range_inclusive(10, &range);
+
+        if let Ok(y) = in_range {
+            assert_eq!(y, 10);
+        } else {
+            panic!("{}", &format!("Expected Ok(10) but got {:?}", in_range));
+        }
+    }
+
+    #[derive(Default)]
+    struct BlockSizes(Vec<usize>);
+
+    impl BlockSolver for BlockSizes {
+        fn handle_block(&mut self, block: &[&str]) -> Result<()> {
+            if block.contains(&"bad") {
+                return Err(CoreError::general("bad block"));
+            }
+            self.0.push(block.len());
+            Ok(())
+        }
+
+        fn extract_solution(&mut self) -> Result<Answer> {
+            Ok(format!("{:?}", self.0).into())
+        }
+    }
+
+    #[test]
+    fn blocks_are_split_on_blank_lines() {
+        let mut solver = BlockSizes::default().solver();
+        solver.handle_input("a\nb\n\n\nc\n  \nd\ne\nf").unwrap();
+        assert_eq!(solver.extract_solution().unwrap(), "[2, 1, 3]");
+
+        let mut solver = BlockSizes::default().solver();
+        for line in ["a", "", "b", "c"] {
+            solver.handle_line(line).unwrap();
+        }
+        assert_eq!(solver.extract_solution().unwrap(), "[1, 2]");
+
+        let mut solver = BlockSizes::default().solver();
+        let err = solver.handle_input("a\n\nb\nbad\n").unwrap_err();
+        assert!(matches!(err, CoreError::Parse { line: 3, .. }));
+    }
+
+    struct Sum;
+
+    impl Puzzle for Sum {
+        type Parsed = Vec<u32>;
+
+        fn parse_line(parsed: &mut Vec<u32>, line: &str) -> Result<()> {
+            parsed.push(line.parse()?);
+            Ok(())
+        }
+
+        fn part_1(parsed: &Vec<u32>) -> Result<Answer> {
+            Ok(parsed.iter().sum::<u32>().into())
+        }
+
+        fn part_2(parsed: &Vec<u32>) -> Result<Answer> {
+            Ok(parsed.iter().product::<u32>().into())
+        }
+    }
+
+    #[test]
+    fn part_2_can_take_the_parsed_input_of_part_1() {
+        let mut first = Sum::solver(PuzzlePart::One);
+        first.handle_input("2\n3\n4").unwrap();
+        assert_eq!(first.extract_solution().unwrap(), "9");
+
+        let mut second = Sum::solver(PuzzlePart::Two);
+        assert!(second.take_parsed(first.as_mut()));
+        assert_eq!(second.extract_solution().unwrap(), "24");
+
+        let mut other = BlockSizes::default().solver();
+        assert!(!Sum::solver(PuzzlePart::Two).take_parsed(other.as_mut()));
+    }
+
+    #[test]
+    fn cancellations_can_have_a_deadline() {
+        let cancellation = Cancellation::default();
+        assert!(!cancellation.is_cancelled());
+        cancellation.cancel();
+        assert!(cancellation.is_cancelled());
+
+        assert!(Cancellation::after(Duration::ZERO).is_cancelled());
+        let cancellation = Cancellation::after(Duration::from_secs(60));
+        assert!(!cancellation.is_cancelled());
+        cancellation.flag().store(true, Ordering::Relaxed);
+        assert!(cancellation.is_cancelled());
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_problem() {
+        let line = "Game 3: x green";
+        let mut scanner = crate::string_scanner::StringScanner::new(line);
+        scanner.expect_string("Game 3: ").unwrap();
+        let source = scanner.expect_uint::<u16>().unwrap_err();
+
+        let error = CoreError::parse(7, line, source.into());
+        assert_eq!(error.column(), Some(8));
+        assert!(error.to_string().ends_with(
+            "
+    Game 3: x green
+            ^"
+        ));
+
+        let error = CoreError::parse(7, line, CoreError::general("bad"));
+        assert_eq!(
+            error.to_string(),
+            "Line 7: General Error: bad\n    Game 3: x green"
+        );
+    }
+
+    #[test]
+    fn answers_match_numerically() {
+        assert!(Answer::from(42u32).matches("42"));
+        assert!(Answer::from(42u32).matches(" 042\n"));
+        assert!(Answer::from(-7i64).matches("-7"));
+        assert!(!Answer::from(42u32).matches("42.0"));
+        assert!(Answer::from("abc").matches("abc\n"));
+        assert!(!Answer::from("abc").matches("ABC"));
+    }
+
+    #[test]
+    fn answers_serialize_as_strings() {
+        assert_eq!(
+            serde_json::to_string(&Answer::from(5905u64)).unwrap(),
+            "\"5905\""
+        );
+        assert_eq!(
+            serde_json::to_string(&Answer::from(-3i32)).unwrap(),
+            "\"-3\""
+        );
+        assert_eq!(serde_json::to_string(&Answer::from("x")).unwrap(), "\"x\"");
+
+        let parse = |json: &str| serde_json::from_str::<Answer>(json).unwrap();
+        assert_eq!(parse("\"6440\""), Answer::Unsigned(6440));
+        assert_eq!(parse("6440"), Answer::Unsigned(6440));
+        assert_eq!(parse("\"-3\""), Answer::Signed(-3));
+        assert_eq!(parse("\"007\""), Answer::Text("007".to_string()));
+        assert_eq!(parse("\"x\""), Answer::Text("x".to_string()));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod cache;
+pub mod checkpoint;
+pub mod core;
+pub mod plugin;
+
+use aoc_utils::{grid, string_scanner};
@@ -0,0 +1,22 @@
+use crate::core::SolverFn;
+
+// Plugins are `cdylib`s that depend on aoc-core and list their solvers with
+// `export_solvers!`. They're loaded with the Rust ABI, so a plugin has to be
+// built with the same compiler and the same aoc-core as the binary loading it.
+pub type Solvers = &'static [(u16, u16, u16, SolverFn)];
+
+pub const SYMBOL: &[u8] = b"aoc_plugin_solvers";
+
+// export_solvers! {
+//     2024, 3, 1 => d03::part_1,
+//     2024, 3, 2 => d03::part_2,
+// }
+#[macro_export]
+macro_rules! export_solvers {
+    ($($year:literal, $day:literal, $part:literal => $solver:path),* $(,)?) => {
+        #[no_mangle]
+        pub fn aoc_plugin_solvers() -> $crate::plugin::Solvers {
+            &[$(($year, $day, $part, $solver)),*]
+        }
+    };
+}
@@ -0,0 +1,165 @@
+use crate::grid::{Connectivity, Grid};
+
+// What a cell on the edge sees when it looks off the grid.
+#[derive(Debug, Clone)]
+pub enum Boundary<T> {
+    // Nothing; edge cells just have fewer neighbours.
+    Clipped,
+    // Everything off the grid is this value.
+    Fixed(T),
+    // The grid wraps round, so the left edge touches the right.
+    Wrap,
+}
+
+// Steps a grid forward a generation at a time, working out each cell's next
+// value from its current one and its neighbours'. Generations are produced
+// lazily by iterating.
+pub struct Automaton<T, R> {
+    grid: Grid<T>,
+    rule: R,
+    connectivity: Connectivity,
+    boundary: Boundary<T>,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone,
+    R: FnMut(&T, &[&T]) -> T,
+{
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Self {
+            grid,
+            rule,
+            connectivity: Connectivity::Eight,
+            boundary: Boundary::Clipped,
+        }
+    }
+
+    pub fn connectivity(mut self, connectivity: Connectivity) -> Self {
+        self.connectivity = connectivity;
+        self
+    }
+
+    pub fn boundary(mut self, boundary: Boundary<T>) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn step(&mut self) {
+        if self.grid.is_empty() {
+            return;
+        }
+        let grid = &self.grid;
+        let tiled = grid.tiled();
+        let mut cells = Vec::with_capacity(grid.len());
+        let mut neighbours = Vec::with_capacity(8);
+
+        for idx in grid.indices() {
+            neighbours.clear();
+            for direction in self.connectivity.directions() {
+                let neighbour = match (grid.neighbour(idx, *direction), &self.boundary) {
+                    (Some(n), _) => &grid[n],
+                    (None, Boundary::Clipped) => continue,
+                    (None, Boundary::Fixed(value)) => value,
+                    (None, Boundary::Wrap) => {
+                        let p = grid.to_point(idx);
+                        let delta = direction.delta();
+                        tiled.get(p.x as i64 + delta.x, p.y as i64 + delta.y)
+                    }
+                };
+                neighbours.push(neighbour);
+            }
+            cells.push((self.rule)(&grid[idx], &neighbours));
+        }
+
+        self.grid = Grid::from_cells(grid.width(), cells).expect("Same size as before");
+    }
+
+    // Keeps stepping until a generation is the same as the one before,
+    // returning that grid and how many steps it took to get there.
+    pub fn run_until_stable(mut self) -> (usize, Grid<T>)
+    where
+        T: PartialEq,
+    {
+        let mut steps = 0;
+        loop {
+            let before = self.grid.clone();
+            self.step();
+            steps += 1;
+            if self.grid == before {
+                return (steps, self.grid);
+            }
+        }
+    }
+}
+
+impl<T, R> Iterator for Automaton<T, R>
+where
+    T: Clone,
+    R: FnMut(&T, &[&T]) -> T,
+{
+    type Item = Grid<T>;
+
+    fn next(&mut self) -> Option<Grid<T>> {
+        self.step();
+        Some(self.grid.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn life(alive: &bool, neighbours: &[&bool]) -> bool {
+        let n = neighbours.iter().filter(|n| ***n).count();
+        n == 3 || (*alive && n == 2)
+    }
+
+    fn parse(lines: &[&str]) -> Grid<bool> {
+        Grid::<char>::from_lines(lines).unwrap().map(|c| *c == '#')
+    }
+
+    #[test]
+    fn blinker_blinks() {
+        let start = parse(&[".....", "..#..", "..#..", "..#..", "....."]);
+        let flipped = parse(&[".....", ".....", ".###.", ".....", "....."]);
+        let generations: Vec<Grid<bool>> = Automaton::new(start.clone(), life).take(4).collect();
+        assert_eq!(
+            generations,
+            vec![flipped.clone(), start.clone(), flipped, start]
+        );
+    }
+
+    #[test]
+    fn can_choose_boundary() {
+        // A glider on a 5x5 torus comes back where it started after 20 steps.
+        let glider = parse(&[".#...", "..#..", "###..", ".....", "....."]);
+        let mut automaton = Automaton::new(glider.clone(), life).boundary(Boundary::Wrap);
+        assert_eq!(automaton.nth(19), Some(glider.clone()));
+
+        // Clipped, it runs into the corner and becomes a block.
+        let automaton = Automaton::new(glider, life);
+        let (_, settled) = automaton.run_until_stable();
+        assert_eq!(settled.iter().filter(|alive| **alive).count(), 4);
+
+        // A live border brings the middle of each edge to life.
+        let empty = Grid::filled(3, 3, false);
+        let mut automaton = Automaton::new(empty, life).boundary(Boundary::Fixed(true));
+        let next = automaton.next().unwrap();
+        assert_eq!(next, parse(&[".#.", "#.#", ".#."]));
+    }
+
+    #[test]
+    fn can_choose_connectivity() {
+        // Spreads to any empty cell next to a full one.
+        let spread = |full: &bool, neighbours: &[&bool]| *full || neighbours.iter().any(|n| **n);
+        let start = parse(&["...", ".#.", "..."]);
+        let mut automaton = Automaton::new(start, spread).connectivity(Connectivity::Four);
+        assert_eq!(automaton.next().unwrap(), parse(&[".#.", "###", ".#."]));
+        assert_eq!(automaton.next().unwrap(), parse(&["###", "###", "###"]));
+    }
+}
@@ -0,0 +1,185 @@
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BitSet(pub u64);
+
+impl BitSet {
+    pub const CAPACITY: usize = 64;
+
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    // The indices 0..n, for any n up to and including CAPACITY.
+    pub fn full(n: usize) -> Self {
+        assert!(
+            n <= Self::CAPACITY,
+            "BitSet can't hold {} items, only {}",
+            n,
+            Self::CAPACITY
+        );
+        match n {
+            Self::CAPACITY => Self(u64::MAX),
+            _ => Self((1 << n) - 1),
+        }
+    }
+
+    pub fn from_index(idx: usize) -> Self {
+        Self(Self::bit(idx))
+    }
+
+    pub fn contains(&self, idx: usize) -> bool {
+        self.0 & Self::bit(idx) != 0
+    }
+
+    pub fn insert(&mut self, idx: usize) {
+        self.0 |= Self::bit(idx);
+    }
+
+    pub fn remove(&mut self, idx: usize) {
+        self.0 &= !Self::bit(idx);
+    }
+
+    pub fn with(&self, idx: usize) -> Self {
+        Self(self.0 | Self::bit(idx))
+    }
+
+    pub fn without(&self, idx: usize) -> Self {
+        Self(self.0 & !Self::bit(idx))
+    }
+
+    // Shifting by 64 or more would overflow, so indices are checked up front.
+    fn bit(idx: usize) -> u64 {
+        assert!(
+            idx < Self::CAPACITY,
+            "BitSet index {} is out of range, it only holds {}",
+            idx,
+            Self::CAPACITY
+        );
+        1 << idx
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(&self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub fn is_subset_of(&self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub fn iter(&self) -> BitIter {
+        BitIter(self.0)
+    }
+
+    pub fn subsets(&self) -> SubsetIter {
+        SubsetIter {
+            mask: self.0,
+            current: Some(self.0),
+        }
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::empty();
+        for idx in iter {
+            set.insert(idx);
+        }
+        set
+    }
+}
+
+// Yields the indices of the set bits, lowest first.
+pub struct BitIter(u64);
+
+impl Iterator for BitIter {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let idx = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+        Some(idx)
+    }
+}
+
+// Yields every subset of the mask (including the mask itself and the empty set),
+// in descending numeric order.
+pub struct SubsetIter {
+    mask: u64,
+    current: Option<u64>,
+}
+
+impl Iterator for SubsetIter {
+    type Item = BitSet;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.current?;
+        self.current = if current == 0 {
+            None
+        } else {
+            Some((current - 1) & self.mask)
+        };
+        Some(BitSet(current))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_convert_between_indices_and_masks() {
+        let set: BitSet = [0, 3, 5].into_iter().collect();
+        assert_eq!(set, BitSet(0b101001));
+        assert_eq!(set.iter().collect::<Vec<usize>>(), vec![0, 3, 5]);
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert_eq!(set.without(3), BitSet(0b100001));
+        assert_eq!(BitSet::full(4), BitSet(0b1111));
+        assert_eq!(BitSet::full(64), BitSet(u64::MAX));
+        assert_eq!(BitSet::full(0), BitSet::empty());
+        assert!(BitSet::full(64).contains(63));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn indices_must_fit() {
+        BitSet::empty().insert(64);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't hold 65 items")]
+    fn full_sets_must_fit() {
+        BitSet::full(65);
+    }
+
+    #[test]
+    fn can_enumerate_subsets() {
+        let set = BitSet(0b1010);
+        let subsets: Vec<BitSet> = set.subsets().collect();
+        assert_eq!(
+            subsets,
+            vec![BitSet(0b1010), BitSet(0b1000), BitSet(0b0010), BitSet(0)]
+        );
+        assert_eq!(BitSet::full(5).subsets().count(), 32);
+        assert!(subsets.iter().all(|s| s.is_subset_of(set)));
+    }
+}
@@ -0,0 +1,158 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Sub},
+};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Counter<T: Hash + Eq>(HashMap<T, usize>);
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.0.entry(item).or_default() += n;
+        }
+    }
+
+    pub fn get(&self, item: &T) -> usize {
+        self.0.get(item).copied().unwrap_or(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn total(&self) -> usize {
+        self.0.values().sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.0.iter().map(|(item, count)| (item, *count))
+    }
+
+    pub fn counts_sorted(&self) -> Vec<usize> {
+        let mut counts: Vec<usize> = self.0.values().copied().collect();
+        counts.sort();
+        counts
+    }
+}
+
+impl<T: Hash + Eq + Ord> Counter<T> {
+    // Highest count first. Items with the same count are in ascending order,
+    // so the result doesn't depend on the HashMap's iteration order.
+    pub fn most_common(&self) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_by(|(a, x), (b, y)| y.cmp(x).then_with(|| a.cmp(b)));
+        items
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Hash + Eq> Add for Counter<T> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        for (item, count) in other.0 {
+            self.add_n(item, count);
+        }
+        self
+    }
+}
+
+// Like python's Counter, counts that drop to zero (or below) are removed.
+impl<T: Hash + Eq> Sub for Counter<T> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        for (item, count) in other.0 {
+            if let Some(current) = self.0.get_mut(&item) {
+                if *current > count {
+                    *current -= count;
+                } else {
+                    self.0.remove(&item);
+                }
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_count_items() {
+        let counter: Counter<char> = "abracadabra".chars().collect();
+        assert_eq!(counter.get(&'a'), 5);
+        assert_eq!(counter.get(&'b'), 2);
+        assert_eq!(counter.get(&'z'), 0);
+        assert_eq!(counter.len(), 5);
+        assert_eq!(counter.total(), 11);
+        assert_eq!(counter.counts_sorted(), vec![1, 1, 2, 2, 5]);
+    }
+
+    #[test]
+    fn most_common_is_in_descending_order() {
+        let counter: Counter<char> = "aaabbc".chars().collect();
+        assert_eq!(counter.most_common(), vec![(&'a', 3), (&'b', 2), (&'c', 1)]);
+    }
+
+    #[test]
+    fn most_common_breaks_ties_by_item() {
+        let counter: Counter<char> = "zyxzyxw".chars().collect();
+        assert_eq!(
+            counter.most_common(),
+            vec![(&'x', 2), (&'y', 2), (&'z', 2), (&'w', 1)]
+        );
+    }
+
+    #[test]
+    fn can_add_and_subtract_counters() {
+        let x: Counter<char> = "aab".chars().collect();
+        let y: Counter<char> = "abc".chars().collect();
+
+        let sum = x.clone() + y.clone();
+        assert_eq!(sum.get(&'a'), 3);
+        assert_eq!(sum.get(&'b'), 2);
+        assert_eq!(sum.get(&'c'), 1);
+
+        let difference = x - y;
+        assert_eq!(difference.get(&'a'), 1);
+        assert_eq!(difference.get(&'b'), 0);
+        assert_eq!(difference.len(), 1);
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use crate::rng::Rng;
+
+// An undirected graph of named nodes. Nodes are numbered in the order they're
+// first seen, and everything other than `index_of` works with those numbers.
+#[derive(Debug, Default, Clone)]
+pub struct Graph {
+    indices: HashMap<String, usize>,
+    adjacency: Vec<Vec<usize>>,
+    edges: Vec<(usize, usize)>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_node(&mut self, name: &str) -> usize {
+        if let Some(&index) = self.indices.get(name) {
+            return index;
+        }
+        let index = self.adjacency.len();
+        self.indices.insert(name.to_string(), index);
+        self.adjacency.push(vec![]);
+        index
+    }
+
+    pub fn add_edge(&mut self, a: &str, b: &str) {
+        let a = self.add_node(a);
+        let b = self.add_node(b);
+        self.adjacency[a].push(b);
+        self.adjacency[b].push(a);
+        self.edges.push((a, b));
+    }
+
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    pub fn neighbours(&self, node: usize) -> &[usize] {
+        &self.adjacency[node]
+    }
+
+    // Karger's algorithm: contracts random edges until only two groups of
+    // nodes are left. The result is only sometimes the minimum cut, so it's
+    // meant to be called repeatedly until a good enough cut turns up.
+    pub fn random_cut(&self, rng: &mut Rng) -> Cut {
+        let mut order: Vec<usize> = (0..self.edges.len()).collect();
+        rng.shuffle(&mut order);
+
+        let mut groups = DisjointSet::new(self.len());
+        let mut remaining = self.len();
+        for i in order {
+            if remaining <= 2 {
+                break;
+            }
+            let (a, b) = self.edges[i];
+            if groups.union(a, b) {
+                remaining -= 1;
+            }
+        }
+
+        let edges = self
+            .edges
+            .iter()
+            .copied()
+            .filter(|&(a, b)| groups.find(a) != groups.find(b))
+            .collect();
+        let first = (!self.is_empty()).then(|| groups.find(0));
+        let size = (0..self.len())
+            .filter(|&n| Some(groups.find(n)) == first)
+            .count();
+        Cut {
+            edges,
+            sizes: (size, self.len() - size),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Cut {
+    pub edges: Vec<(usize, usize)>,
+    pub sizes: (usize, usize),
+}
+
+#[derive(Debug)]
+struct DisjointSet(Vec<usize>);
+
+impl DisjointSet {
+    fn new(len: usize) -> Self {
+        Self((0..len).collect())
+    }
+
+    fn find(&mut self, node: usize) -> usize {
+        let mut root = node;
+        while self.0[root] != root {
+            root = self.0[root];
+        }
+        let mut node = node;
+        while self.0[node] != root {
+            node = std::mem::replace(&mut self.0[node], root);
+        }
+        root
+    }
+
+    // Returns false if the two were already in the same set.
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        self.0[a] = b;
+        a != b
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Two triangles joined by a single edge.
+    fn bowtie() -> Graph {
+        let mut graph = Graph::new();
+        for (a, b) in [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("x", "y"),
+            ("y", "z"),
+            ("z", "x"),
+            ("c", "x"),
+        ] {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn can_build_from_names() {
+        let graph = bowtie();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.edges().len(), 7);
+        assert_eq!(graph.index_of("c"), Some(2));
+        assert_eq!(graph.index_of("q"), None);
+        assert_eq!(graph.neighbours(2), &[1, 0, 3]);
+    }
+
+    #[test]
+    fn random_cuts_split_the_graph_in_two() {
+        let graph = bowtie();
+        let mut rng = Rng::new(1);
+        let cuts: Vec<Cut> = (0..50).map(|_| graph.random_cut(&mut rng)).collect();
+        assert!(cuts.iter().all(|cut| cut.sizes.0 + cut.sizes.1 == 6));
+        assert!(cuts.iter().all(|cut| !cut.edges.is_empty()));
+
+        let best = cuts.iter().min_by_key(|cut| cut.edges.len()).unwrap();
+        assert_eq!(best.edges, vec![(2, 3)]);
+        assert_eq!(best.sizes, (3, 3));
+
+        let cut = Graph::new().random_cut(&mut rng);
+        assert_eq!(cut.sizes, (0, 0));
+    }
+}
@@ -0,0 +1,1645 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    iter::StepBy,
+    ops::{Add, Index, IndexMut, Mul, Range, Sub},
+};
+
+use thiserror::Error;
+
+pub mod hex;
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum GridError {
+    #[error("Row {row} has {found} cells but the first has {expected}")]
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("{cells} cells can't be split into rows of {width}")]
+    WrongSize { cells: usize, width: usize },
+    #[error("Bad cell {c:?} at ({x}, {y}): {reason}")]
+    BadCell {
+        x: usize,
+        y: usize,
+        c: char,
+        reason: String,
+    },
+    #[error("Unknown direction {0:?}")]
+    BadDirection(String),
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Direction {
+    NorthWest,
+    North,
+    NorthEast,
+    West,
+    East,
+    SouthWest,
+    South,
+    SouthEast,
+}
+
+impl Direction {
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::NorthWest,
+            Self::North,
+            Self::NorthEast,
+            Self::West,
+            Self::East,
+            Self::SouthWest,
+            Self::South,
+            Self::SouthEast,
+        ]
+    }
+
+    pub fn cardinal() -> &'static [Self] {
+        &[Self::North, Self::East, Self::South, Self::West]
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    // Turns by 90 degrees, so diagonals stay diagonal.
+    pub fn turn_right(&self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::NorthEast => Self::SouthEast,
+            Self::East => Self::South,
+            Self::SouthEast => Self::SouthWest,
+            Self::South => Self::West,
+            Self::SouthWest => Self::NorthWest,
+            Self::West => Self::North,
+            Self::NorthWest => Self::NorthEast,
+        }
+    }
+
+    pub fn turn_left(&self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    // One step in this direction, with y growing southwards.
+    pub fn delta(&self) -> IPoint {
+        let (x, y) = match self {
+            Self::NorthWest => (-1, -1),
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::West => (-1, 0),
+            Self::East => (1, 0),
+            Self::SouthWest => (-1, 1),
+            Self::South => (0, 1),
+            Self::SouthEast => (1, 1),
+        };
+        IPoint::new(x, y)
+    }
+}
+
+// Accepts both compass letters and the U/D/L/R of movement instructions.
+impl TryFrom<char> for Direction {
+    type Error = GridError;
+
+    fn try_from(c: char) -> Result<Self, GridError> {
+        let direction = match c {
+            'N' | 'U' => Self::North,
+            'S' | 'D' => Self::South,
+            'E' | 'R' => Self::East,
+            'W' | 'L' => Self::West,
+            _ => return Err(GridError::BadDirection(c.to_string())),
+        };
+        Ok(direction)
+    }
+}
+
+// Whether cells touch only along their edges, or at the corners as well.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn directions(&self) -> &'static [Direction] {
+        match self {
+            Self::Four => Direction::cardinal(),
+            Self::Eight => Direction::all(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn points(self) -> impl Iterator<Item = Point> {
+        (self.y..self.y + self.height)
+            .flat_map(move |y| (self.x..self.x + self.width).map(move |x| Point::new(x, y)))
+    }
+}
+
+// Remaps points as if `extra` copies of some rows and columns had been
+// inserted (just before each of them, like `Grid::insert_rows`), for when the
+// expanded grid would be far too big to actually build.
+#[derive(Debug, Clone)]
+pub struct Expansion {
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    extra: usize,
+}
+
+impl Expansion {
+    pub fn new(mut rows: Vec<usize>, mut columns: Vec<usize>, extra: usize) -> Self {
+        rows.sort_unstable();
+        columns.sort_unstable();
+        Self {
+            rows,
+            columns,
+            extra,
+        }
+    }
+
+    pub fn point(&self, point: &Point) -> Point {
+        let rows_before = self.rows.partition_point(|y| *y <= point.y);
+        let columns_before = self.columns.partition_point(|x| *x <= point.x);
+        Point::new(
+            point.x + columns_before * self.extra,
+            point.y + rows_before * self.extra,
+        )
+    }
+
+    // The (width, height) of a grid this size once expanded.
+    pub fn size(&self, width: usize, height: usize) -> (usize, usize) {
+        (
+            width + self.columns.len() * self.extra,
+            height + self.rows.len() * self.extra,
+        )
+    }
+}
+
+// Every passable cell labelled with which region it belongs to, as an index
+// into `sizes`. Impassable cells have no label.
+#[derive(Debug, Clone)]
+pub struct Components {
+    pub labels: Grid<Option<usize>>,
+    pub sizes: Vec<usize>,
+}
+
+// How a cell was reached by `Grid::distance_field`: how far it is from the
+// nearest source, and which one (by its place in the list of sources) that
+// was. If several sources are equally near, `tied` is set and `source` is
+// just one of them.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Reached {
+    pub distance: usize,
+    pub source: usize,
+    pub tied: bool,
+}
+
+// Something on a grid facing a particular way, for following instructions.
+// Comparable and hashable so states can be remembered to spot loops.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cursor {
+    pub position: Point,
+    pub heading: Direction,
+}
+
+impl Cursor {
+    pub fn new(position: Point, heading: Direction) -> Self {
+        Self { position, heading }
+    }
+
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.turn_left();
+    }
+
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.turn_right();
+    }
+
+    pub fn turn_around(&mut self) {
+        self.heading = self.heading.opposite();
+    }
+
+    // The point one step ahead, or None if that's off the grid.
+    pub fn ahead<T>(&self, grid: &Grid<T>) -> Option<Point> {
+        let idx = grid.to_index(&self.position);
+        grid.neighbour(idx, self.heading).map(|n| grid.to_point(n))
+    }
+
+    // Moves one step forward unless that would leave the grid, in which case
+    // it stays put. Returns whether it moved.
+    pub fn step<T>(&mut self, grid: &Grid<T>) -> bool {
+        match self.ahead(grid) {
+            Some(position) => {
+                self.position = position;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+// Like `Point` but free to go below zero. As on the grid, y grows downwards,
+// so rotating clockwise turns east into south.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct IPoint {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl IPoint {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
+impl Add for IPoint {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for IPoint {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for IPoint {
+    type Output = Self;
+
+    fn mul(self, n: i64) -> Self {
+        Self::new(self.x * n, self.y * n)
+    }
+}
+
+impl From<Point> for IPoint {
+    fn from(point: Point) -> Self {
+        Self::new(point.x as i64, point.y as i64)
+    }
+}
+
+// How many places two rows (or columns, or any runs of cells) differ. If one
+// is longer, its extra cells all count as differences.
+pub fn count_diffs<'a, T, A, B>(a: A, b: B) -> usize
+where
+    T: PartialEq + 'a,
+    A: IntoIterator<Item = &'a T>,
+    B: IntoIterator<Item = &'a T>,
+{
+    let (mut a, mut b) = (a.into_iter(), b.into_iter());
+    let mut diffs = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return diffs,
+            (Some(x), Some(y)) if x == y => {}
+            _ => diffs += 1,
+        }
+    }
+}
+
+// Twice the area inside a closed loop of vertices given in order, by the
+// shoelace formula; doubled so it's always a whole number. Goes round
+// clockwise or anticlockwise, either works.
+pub fn polygon_twice_area(points: &[IPoint]) -> u128 {
+    let Some(last) = points.last() else {
+        return 0;
+    };
+    let twice_area: i128 = std::iter::once(last)
+        .chain(points)
+        .zip(points)
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum();
+    twice_area.unsigned_abs()
+}
+
+// By Pick's theorem, the number of lattice points strictly inside a polygon
+// whose corners are on the lattice, given twice its area (as from
+// `polygon_twice_area`) and how many lattice points lie on its boundary.
+pub fn interior_points(twice_area: u128, boundary_len: u64) -> u128 {
+    (twice_area + 2).saturating_sub(boundary_len as u128) / 2
+}
+
+// Cells are stored row by row, so a cell's index is `y * width + x`. A plain
+// `Grid` has no contents, just the geometry.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid<T = ()> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self::filled(width, height, ())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    // A copy of the cells inside `rect`, or None if it doesn't fit.
+    pub fn subgrid(&self, rect: &Rect) -> Option<Self> {
+        if !self.covers(rect) {
+            return None;
+        }
+        let cells = rect.points().map(|p| self[p].clone()).collect();
+        Some(Self {
+            width: rect.width,
+            height: rect.height,
+            cells,
+        })
+    }
+
+    // Adds `n` rows of `fill` just before row `at`, or at the bottom if `at`
+    // is the height.
+    pub fn insert_rows(&mut self, at: usize, n: usize, fill: T) {
+        assert!(at <= self.height, "Row {} is off the grid", at);
+        let start = at * self.width;
+        let new_cells = std::iter::repeat_n(fill, n * self.width);
+        self.cells.splice(start..start, new_cells);
+        self.height += n;
+    }
+
+    // Adds `n` columns of `fill` just before column `at`, or on the right if
+    // `at` is the width.
+    pub fn insert_columns(&mut self, at: usize, n: usize, fill: T) {
+        assert!(at <= self.width, "Column {} is off the grid", at);
+        let width = self.width + n;
+        let mut cells = Vec::with_capacity(width * self.height);
+        for y in 0..self.height {
+            let row = self.row(y);
+            cells.extend_from_slice(&row[..at]);
+            cells.extend(std::iter::repeat_n(fill.clone(), n));
+            cells.extend_from_slice(&row[at..]);
+        }
+        self.cells = cells;
+        self.width = width;
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.rearranged(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        self.rearranged(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    // Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.rearranged(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    // Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.rearranged(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+
+    // Builds a grid of the given size where the cell at (x, y) is copied from
+    // the cell at `source(x, y)` in this one.
+    fn rearranged<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let (sx, sy) = source(x, y);
+                self.cells[sy * self.width + sx].clone()
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Result<Self, GridError> {
+        if width == 0 || !cells.len().is_multiple_of(width) {
+            let cells = cells.len();
+            return Err(GridError::WrongSize { cells, width });
+        }
+        Ok(Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        })
+    }
+
+    pub fn from_rows<I, R>(rows: I) -> Result<Self, GridError>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (row, cells_in_row) in rows.into_iter().enumerate() {
+            let before = cells.len();
+            cells.extend(cells_in_row);
+            let found = cells.len() - before;
+            if row == 0 {
+                width = found;
+            } else if found != width {
+                return Err(GridError::RaggedRow {
+                    row,
+                    expected: width,
+                    found,
+                });
+            }
+            height += 1;
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    // Each char becomes one cell, so all lines must be the same length.
+    pub fn from_lines<I>(lines: I) -> Result<Self, GridError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+        T: TryFrom<char>,
+        T::Error: std::fmt::Display,
+    {
+        let rows = lines
+            .into_iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        T::try_from(c).map_err(|e| GridError::BadCell {
+                            x,
+                            y,
+                            c,
+                            reason: e.to_string(),
+                        })
+                    })
+                    .collect::<Result<Vec<T>, GridError>>()
+            })
+            .collect::<Result<Vec<_>, GridError>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, point: &Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[self.to_index(point)])
+    }
+
+    pub fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        let idx = self.to_index(point);
+        self.contains(point).then(|| &mut self.cells[idx])
+    }
+
+    // Returns the old value, or None (leaving the grid alone) if the point
+    // isn't on the grid.
+    pub fn set(&mut self, point: &Point, value: T) -> Option<T> {
+        self.get_mut(point)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    // One line per row, each ending in a newline.
+    pub fn render<F: FnMut(&T) -> char>(&self, mut f: F) -> String {
+        self.render_highlighted(&HashSet::new(), |cell, _| f(cell))
+    }
+
+    // As `render`, but also tells the formatter whether each cell's index is
+    // in `highlighted`.
+    pub fn render_highlighted<F>(&self, highlighted: &HashSet<usize>, mut f: F) -> String
+    where
+        F: FnMut(&T, bool) -> char,
+    {
+        let mut s = String::with_capacity(self.len() + self.height);
+        for (idx, cell) in self.cells.iter().enumerate() {
+            s.push(f(cell, highlighted.contains(&idx)));
+            if (idx + 1).is_multiple_of(self.width) {
+                s.push('\n');
+            }
+        }
+        s
+    }
+
+    pub fn tiled(&self) -> Tiled<'_, T> {
+        Tiled(self)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn to_point(&self, idx: usize) -> Point {
+        let width = self.width();
+        let x = idx % width;
+        let y = idx / width;
+        Point { x, y }
+    }
+
+    pub fn to_index(&self, point: &Point) -> usize {
+        let Point { x, y } = point;
+        let width = self.width;
+        y * width + x
+    }
+
+    pub fn neighbour(&self, idx: usize, direction: Direction) -> Option<usize> {
+        let (width, height) = (self.width, self.height);
+        let Point { x, y } = self.to_point(idx);
+        let max_x = width - 1;
+        let max_y = height - 1;
+        use Direction::*;
+
+        let (new_x, new_y) = match direction {
+            North if y > 0 => (x, y - 1),
+            South if y < max_y => (x, y + 1),
+            West if x > 0 => (x - 1, y),
+            East if x < max_x => (x + 1, y),
+            NorthWest if (x > 0 && y > 0) => (x - 1, y - 1),
+            NorthEast if (x < max_x && y > 0) => (x + 1, y - 1),
+            SouthWest if (x > 0 && y < max_y) => (x - 1, y + 1),
+            SouthEast if (x < max_x && y < max_y) => (x + 1, y + 1),
+            _ => {
+                return None;
+            }
+        };
+
+        Some(self.to_index(&Point::new(new_x, new_y)))
+    }
+
+    pub fn neighbours(&self, idx: usize) -> Vec<usize> {
+        Direction::all()
+            .iter()
+            .filter_map(|direction| self.neighbour(idx, *direction))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn indices(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    // Every index reachable from `start` by stepping between passable cells.
+    // Empty if `start` itself isn't passable.
+    pub fn flood_fill<F>(
+        &self,
+        start: usize,
+        connectivity: Connectivity,
+        passable: F,
+    ) -> HashSet<usize>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut reached = HashSet::new();
+        if !passable(&self.cells[start]) {
+            return reached;
+        }
+        reached.insert(start);
+        let mut queue = VecDeque::from([start]);
+        while let Some(idx) = queue.pop_front() {
+            for direction in connectivity.directions() {
+                if let Some(next) = self.neighbour(idx, *direction) {
+                    if passable(&self.cells[next]) && reached.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        reached
+    }
+
+    // The indices met stepping out from `start` (which isn't included) until
+    // falling off the edge.
+    pub fn walk(&self, start: usize, direction: Direction) -> impl Iterator<Item = usize> + '_ {
+        std::iter::successors(self.neighbour(start, direction), move |idx| {
+            self.neighbour(*idx, direction)
+        })
+    }
+
+    // As `walk`, but also stops after the first cell matching `stop`. That
+    // cell is still yielded, as the thing that blocked the way.
+    pub fn walk_until<'a, F>(
+        &'a self,
+        start: usize,
+        direction: Direction,
+        stop: F,
+    ) -> impl Iterator<Item = usize> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        let mut stopped = false;
+        self.walk(start, direction).take_while(move |idx| {
+            let carry_on = !stopped;
+            stopped = stopped || stop(&self.cells[*idx]);
+            carry_on
+        })
+    }
+
+    pub fn covers(&self, rect: &Rect) -> bool {
+        rect.x + rect.width <= self.width && rect.y + rect.height <= self.height
+    }
+
+    pub fn cells_in<'a>(&'a self, rect: &Rect) -> impl Iterator<Item = &'a T> + 'a {
+        assert!(self.covers(rect), "{:?} is off the grid", rect);
+        rect.points().map(|p| &self[p])
+    }
+
+    // Every `width` x `height` rectangle that fits on the grid, left to right
+    // then top to bottom. There are none if either size is zero.
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Rect> {
+        let xs = (self.width + 1).saturating_sub(width);
+        let ys = match width == 0 || height == 0 {
+            true => 0,
+            false => (self.height + 1).saturating_sub(height),
+        };
+        (0..ys).flat_map(move |y| (0..xs).map(move |x| Rect::new(x, y, width, height)))
+    }
+
+    // The 3x3 block centred on `idx`, row by row, with None for anything off
+    // the edge.
+    pub fn kernel(&self, idx: usize) -> [Option<&T>; 9] {
+        let Point { x, y } = self.to_point(idx);
+        let mut kernel = [None; 9];
+        for (i, cell) in kernel.iter_mut().enumerate() {
+            let (nx, ny) = ((x + i % 3).checked_sub(1), (y + i / 3).checked_sub(1));
+            if let (Some(nx), Some(ny)) = (nx, ny) {
+                *cell = self.get(&Point::new(nx, ny));
+            }
+        }
+        kernel
+    }
+
+    // How many of the (up to 8) surrounding cells match.
+    pub fn count_neighbours<F: Fn(&T) -> bool>(&self, idx: usize, predicate: F) -> usize {
+        self.neighbours(idx)
+            .into_iter()
+            .filter(|n| predicate(&self.cells[*n]))
+            .count()
+    }
+
+    // Slides every movable cell as far as it'll go in a (cardinal) direction,
+    // stopping at the edge, a solid cell or a movable one that's already
+    // stopped. Cells that are neither are empty space and get pushed the other
+    // way. Returns whether anything moved.
+    pub fn tilt<M, S>(&mut self, direction: Direction, movable: M, solid: S) -> bool
+    where
+        M: Fn(&T) -> bool,
+        S: Fn(&T) -> bool,
+    {
+        let lanes: Vec<Vec<usize>> = match direction {
+            Direction::North => (0..self.width)
+                .map(|x| self.column_indices(x).collect())
+                .collect(),
+            Direction::South => (0..self.width)
+                .map(|x| self.column_indices(x).rev().collect())
+                .collect(),
+            Direction::West => (0..self.height)
+                .map(|y| self.row_indices(y).collect())
+                .collect(),
+            Direction::East => (0..self.height)
+                .map(|y| self.row_indices(y).rev().collect())
+                .collect(),
+            _ => panic!(
+                "Can only tilt north, south, east or west, not {:?}",
+                direction
+            ),
+        };
+
+        let mut moved = false;
+        for lane in lanes {
+            let mut landing = 0;
+            for (i, idx) in lane.iter().enumerate() {
+                let cell = &self.cells[*idx];
+                if solid(cell) {
+                    landing = i + 1;
+                } else if movable(cell) {
+                    if landing != i {
+                        self.cells.swap(lane[landing], *idx);
+                        moved = true;
+                    }
+                    landing += 1;
+                }
+            }
+        }
+        moved
+    }
+
+    // Mismatches between the rows either side of a horizontal mirror placed
+    // just above row `y`, going out until one side runs off the grid. Zero
+    // means a perfect reflection.
+    pub fn row_reflection_diffs(&self, y: usize) -> usize
+    where
+        T: PartialEq,
+    {
+        let above = (0..y).rev();
+        let below = y..self.height;
+        above
+            .zip(below)
+            .map(|(a, b)| count_diffs(self.row(a), self.row(b)))
+            .sum()
+    }
+
+    // As `row_reflection_diffs`, for a vertical mirror just left of column `x`.
+    pub fn column_reflection_diffs(&self, x: usize) -> usize
+    where
+        T: PartialEq,
+    {
+        let left = (0..x).rev();
+        let right = x..self.width;
+        left.zip(right)
+            .map(|(a, b)| count_diffs(self.column(a), self.column(b)))
+            .sum()
+    }
+
+    pub fn find<F: Fn(&T) -> bool>(&self, predicate: F) -> Option<usize> {
+        self.cells.iter().position(predicate)
+    }
+
+    pub fn positions_of<'a, F>(&'a self, predicate: F) -> impl Iterator<Item = GridPosition> + 'a
+    where
+        F: Fn(&T) -> bool + 'a,
+    {
+        self.positions()
+            .filter(move |position| predicate(&self.cells[position.index]))
+    }
+
+    // Splits the passable cells into connected regions, numbered in the
+    // order their first cell appears.
+    pub fn components<F>(&self, connectivity: Connectivity, passable: F) -> Components
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut labels = self.map(|_| None);
+        let mut sizes = vec![];
+        for idx in self.indices() {
+            if labels[idx].is_some() || !passable(&self.cells[idx]) {
+                continue;
+            }
+            let region = self.flood_fill(idx, connectivity, &passable);
+            for member in region.iter() {
+                labels[*member] = Some(sizes.len());
+            }
+            sizes.push(region.len());
+        }
+        Components { labels, sizes }
+    }
+
+    // Breadth-first search spreading out from all the sources at once, giving
+    // each reachable cell its distance to the nearest one. Sources count as
+    // reached even if they aren't passable themselves.
+    pub fn distance_field<F>(
+        &self,
+        sources: &[usize],
+        connectivity: Connectivity,
+        passable: F,
+    ) -> Grid<Option<Reached>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut field = self.map(|_| None);
+        let mut queue = VecDeque::new();
+        for (source, idx) in sources.iter().enumerate() {
+            if field[*idx].is_none() {
+                field[*idx] = Some(Reached {
+                    distance: 0,
+                    source,
+                    tied: false,
+                });
+                queue.push_back(*idx);
+            }
+        }
+
+        while let Some(idx) = queue.pop_front() {
+            let Some(here) = field[idx] else {
+                continue;
+            };
+            for direction in connectivity.directions() {
+                let Some(next) = self.neighbour(idx, *direction) else {
+                    continue;
+                };
+                if !passable(&self.cells[next]) {
+                    continue;
+                }
+                let distance = here.distance + 1;
+                match &mut field[next] {
+                    cell @ None => {
+                        *cell = Some(Reached { distance, ..here });
+                        queue.push_back(next);
+                    }
+                    // Everything at one distance is finished with before
+                    // anything further away, so ties are settled in time.
+                    Some(there) if there.distance == distance => {
+                        there.tied |= here.tied || there.source != here.source;
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        field
+    }
+
+    pub fn is_on_edge(&self, idx: usize) -> bool {
+        let Point { x, y } = self.to_point(idx);
+        x == 0 || y == 0 || x + 1 == self.width || y + 1 == self.height
+    }
+
+    // Each edge cell once, clockwise from the top left corner.
+    pub fn border_indices(&self) -> Vec<usize> {
+        if self.is_empty() {
+            return vec![];
+        }
+        let (right, bottom) = (self.width - 1, self.height - 1);
+        let top = self.row_indices(0);
+        let down = self.column_indices(right).skip(1);
+        let mut border: Vec<usize> = top.chain(down).collect();
+        if bottom > 0 {
+            border.extend(self.row_indices(bottom).rev().skip(1));
+        }
+        if right > 0 {
+            let up = self.column_indices(0).rev().skip(1);
+            border.extend(up.take(bottom.saturating_sub(1)));
+        }
+        border
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[self.row_indices(y)]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.column_indices(x).map(|idx| &self.cells[idx])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn row_indices(&self, y: usize) -> Range<usize> {
+        assert!(y < self.height, "Row {} is off the grid", y);
+        y * self.width..(y + 1) * self.width
+    }
+
+    pub fn column_indices(&self, x: usize) -> StepBy<Range<usize>> {
+        assert!(x < self.width, "Column {} is off the grid", x);
+        (x..self.len()).step_by(self.width)
+    }
+
+    pub fn positions(&self) -> GridPositionIter<'_, T> {
+        GridPositionIter::new(self, Order::RowMajor)
+    }
+
+    // Down the first column, then down the second, and so on.
+    pub fn positions_by_column(&self) -> GridPositionIter<'_, T> {
+        GridPositionIter::new(self, Order::ColumnMajor)
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T {
+        &self.cells[idx]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, idx: usize) -> &mut T {
+        &mut self.cells[idx]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        assert!(self.contains(&point), "{:?} is off the grid", point);
+        &self.cells[self.to_index(&point)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        assert!(self.contains(&point), "{:?} is off the grid", point);
+        let idx = self.to_index(&point);
+        &mut self.cells[idx]
+    }
+}
+
+// A view of a grid repeated forever in every direction, so any signed
+// coordinate lands on some cell of the original.
+#[derive(Debug, Clone, Copy)]
+pub struct Tiled<'a, T>(&'a Grid<T>);
+
+impl<'a, T> Tiled<'a, T> {
+    pub fn wrap(&self, x: i64, y: i64) -> Point {
+        let (width, height) = (self.0.width as i64, self.0.height as i64);
+        Point::new(x.rem_euclid(width) as usize, y.rem_euclid(height) as usize)
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> &'a T {
+        let grid = self.0;
+        &grid.cells[grid.to_index(&self.wrap(x, y))]
+    }
+
+    // Which copy of the grid (x, y) falls in, with (0, 0) being the original.
+    pub fn tile_of(&self, x: i64, y: i64) -> (i64, i64) {
+        let (width, height) = (self.0.width as i64, self.0.height as i64);
+        (x.div_euclid(width), y.div_euclid(height))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub struct GridPosition {
+    pub index: usize,
+    pub x: usize,
+    pub y: usize,
+}
+
+impl GridPosition {
+    fn new(index: usize, x: usize, y: usize) -> Self {
+        Self { index, x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Order {
+    RowMajor,
+    ColumnMajor,
+}
+
+// Counts through the positions from both ends, so `front..back` are the ones
+// still to come.
+pub struct GridPositionIter<'a, T> {
+    grid: &'a Grid<T>,
+    order: Order,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> GridPositionIter<'a, T> {
+    fn new(grid: &'a Grid<T>, order: Order) -> Self {
+        Self {
+            grid,
+            order,
+            front: 0,
+            back: grid.len(),
+        }
+    }
+
+    // Pairs each position with what's in the cell there.
+    pub fn with_values(self) -> impl DoubleEndedIterator<Item = (GridPosition, &'a T)> {
+        let grid = self.grid;
+        self.map(move |position| {
+            let value = &grid.cells[position.index];
+            (position, value)
+        })
+    }
+
+    fn nth_position(&self, n: usize) -> GridPosition {
+        let (width, height) = (self.grid.width, self.grid.height);
+        let (x, y) = match self.order {
+            Order::RowMajor => (n % width, n / width),
+            Order::ColumnMajor => (n / height, n % height),
+        };
+        GridPosition::new(y * width + x, x, y)
+    }
+}
+
+impl<'a, T> Iterator for GridPositionIter<'a, T> {
+    type Item = GridPosition;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        let position = self.nth_position(self.front);
+        self.front += 1;
+
+        Some(position)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for GridPositionIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.nth_position(self.back))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for GridPositionIter<'a, T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_get_neighbours() {
+        /*
+         * 0123
+         * 4567
+         * 89ab
+         */
+        let grid = Grid::new(4, 3);
+        assert_eq!(grid.neighbours(0), vec![1, 4, 5]);
+        assert_eq!(grid.neighbours(5), vec![0, 1, 2, 4, 6, 8, 9, 10]);
+        assert_eq!(grid.neighbours(10), vec![5, 6, 7, 9, 11]);
+    }
+
+    #[test]
+    fn can_do_signed_point_arithmetic() {
+        let east = IPoint::new(1, 0);
+        assert_eq!(east.rotate_cw(), IPoint::new(0, 1));
+        assert_eq!(east.rotate_ccw(), IPoint::new(0, -1));
+        assert_eq!(east.rotate_cw().rotate_cw().rotate_cw(), east.rotate_ccw());
+
+        let p = IPoint::from(Point::new(2, 3)) - east * 5;
+        assert_eq!(p, IPoint::new(-3, 3));
+        assert_eq!(p + IPoint::new(3, -3), IPoint::default());
+        assert_eq!(p.manhattan(&IPoint::new(1, -1)), 8);
+    }
+
+    #[test]
+    fn can_turn_and_parse_directions() {
+        use Direction::*;
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(NorthEast.turn_left(), NorthWest);
+        assert_eq!(SouthWest.opposite(), NorthEast);
+        for direction in Direction::all() {
+            assert_eq!(direction.turn_left().turn_right(), *direction);
+            assert_eq!(
+                direction.delta() + direction.opposite().delta(),
+                IPoint::default()
+            );
+            assert_eq!(
+                direction.turn_right().delta(),
+                direction.delta().rotate_cw()
+            );
+        }
+
+        let parsed: Vec<Direction> = "URDLNESW".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(
+            parsed,
+            vec![North, East, South, West, North, East, South, West]
+        );
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn can_measure_polygons() {
+        let square = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| IPoint::new(x, y));
+        assert_eq!(polygon_twice_area(&square), 32);
+        assert_eq!(interior_points(32, 16), 9);
+
+        let mut backwards = square;
+        backwards.reverse();
+        assert_eq!(polygon_twice_area(&backwards), 32);
+
+        let triangle = [(0, 0), (3, 0), (0, 1)].map(|(x, y)| IPoint::new(x, y));
+        assert_eq!(polygon_twice_area(&triangle), 3);
+        assert_eq!(polygon_twice_area(&[]), 0);
+
+        // Far too big to be exact as an f64.
+        let huge = 1 << 40;
+        let square = [(0, 0), (huge, 0), (huge, huge), (0, huge)].map(|(x, y)| IPoint::new(x, y));
+        assert_eq!(polygon_twice_area(&square), 2 << 80);
+        assert_eq!(
+            interior_points(2 << 80, 4 * huge as u64),
+            (huge as u128 - 1).pow(2)
+        );
+    }
+
+    #[test]
+    fn can_build_from_rows() {
+        let grid = Grid::from_rows(["abc".chars(), "def".chars()]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(&Point::new(1, 1)), Some(&'e'));
+        assert_eq!(grid.get(&Point::new(3, 0)), None);
+        assert_eq!(grid[2], 'c');
+        assert_eq!(
+            grid,
+            Grid::from_cells(3, "abcdef".chars().collect()).unwrap()
+        );
+
+        let err = Grid::from_rows(["abc".chars(), "de".chars()]).unwrap_err();
+        assert_eq!(
+            err,
+            GridError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert!(Grid::from_cells(4, vec![0; 6]).is_err());
+        assert!(Grid::<u8>::from_cells(0, vec![]).is_err());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Digit(u32);
+
+    impl TryFrom<char> for Digit {
+        type Error = String;
+
+        fn try_from(c: char) -> Result<Self, String> {
+            c.to_digit(10).map(Digit).ok_or("not a digit".to_string())
+        }
+    }
+
+    #[test]
+    fn can_parse_lines() {
+        let grid = Grid::<Digit>::from_lines(["12", "34"]).unwrap();
+        assert_eq!(grid[Point::new(0, 1)], Digit(3));
+
+        let chars = Grid::<char>::from_lines(vec!["ab".to_string()]).unwrap();
+        assert_eq!(chars.cells(), &['a', 'b']);
+
+        let err = Grid::<Digit>::from_lines(["12", "3x"]).unwrap_err();
+        assert_eq!(err.to_string(), "Bad cell 'x' at (1, 1): not a digit");
+    }
+
+    #[test]
+    fn can_render() {
+        let grid = Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let digit = |n: &u32| char::from_digit(*n, 10).unwrap();
+        assert_eq!(grid.render(digit), "123\n456\n");
+
+        let highlighted = HashSet::from([1, 5]);
+        let rendered = grid.render_highlighted(&highlighted, |n, lit| match lit {
+            true => '*',
+            false => digit(n),
+        });
+        assert_eq!(rendered, "1*3\n45*\n");
+    }
+
+    #[test]
+    fn can_rotate_and_flip() {
+        // abc
+        // def
+        let grid = Grid::from_lines(["abc", "def"]).unwrap();
+        let render = |g: Grid<char>| g.render(|c| *c);
+        assert_eq!(render(grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(render(grid.rotate_cw()), "da\neb\nfc\n");
+        assert_eq!(render(grid.rotate_ccw()), "cf\nbe\nad\n");
+        assert_eq!(render(grid.flip_horizontal()), "cba\nfed\n");
+        assert_eq!(render(grid.flip_vertical()), "def\nabc\n");
+
+        let spun = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(spun, grid);
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(
+            grid.rotate_cw().rotate_cw(),
+            grid.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn can_iterate_over_rows_and_columns() {
+        let grid = Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), vec![&3, &6]);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 2, 3], &[4, 5, 6]]
+        );
+
+        let sums: Vec<i32> = grid.columns().map(|column| column.sum()).collect();
+        assert_eq!(sums, vec![5, 7, 9]);
+
+        assert_eq!(grid.row_indices(1), 3..6);
+        assert_eq!(grid.column_indices(1).collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(Grid::new(0, 0).rows().count(), 0);
+    }
+
+    #[test]
+    fn can_flood_fill() {
+        let grid = Grid::<char>::from_lines(["..#.", ".#..", "#..."]).unwrap();
+        let open = |c: &char| *c == '.';
+
+        let reached = grid.flood_fill(0, Connectivity::Four, open);
+        assert_eq!(reached, HashSet::from([0, 1, 4]));
+
+        let reached = grid.flood_fill(0, Connectivity::Eight, open);
+        assert_eq!(reached.len(), 9);
+
+        assert!(grid.flood_fill(2, Connectivity::Four, open).is_empty());
+    }
+
+    #[test]
+    fn can_view_as_tiled() {
+        let grid = Grid::from_cells(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let tiled = grid.tiled();
+        assert_eq!(*tiled.get(0, 0), 1);
+        assert_eq!(*tiled.get(4, 3), 5);
+        assert_eq!(*tiled.get(-1, -1), 6);
+        assert_eq!(*tiled.get(-7, 10), 3);
+        assert_eq!(tiled.wrap(-1, 2), Point::new(2, 0));
+        assert_eq!(tiled.tile_of(2, 1), (0, 0));
+        assert_eq!(tiled.tile_of(-1, 5), (-1, 2));
+    }
+
+    #[test]
+    fn can_find_cells() {
+        let grid = Grid::<char>::from_lines([".#.", "..#"]).unwrap();
+        assert_eq!(grid.find(|c| *c == '#'), Some(1));
+        assert_eq!(grid.find(|c| *c == 'x'), None);
+
+        let found: Vec<GridPosition> = grid.positions_of(|c| *c == '#').collect();
+        assert_eq!(
+            found,
+            vec![GridPosition::new(1, 1, 0), GridPosition::new(5, 2, 1)]
+        );
+    }
+
+    #[test]
+    fn can_walk_in_a_direction() {
+        /*
+         * 0123
+         * 4567
+         * 89ab
+         */
+        let grid = Grid::from_cells(4, (0..12).collect()).unwrap();
+        let walk = |start, direction| grid.walk(start, direction).collect::<Vec<_>>();
+        assert_eq!(walk(4, Direction::East), vec![5, 6, 7]);
+        assert_eq!(walk(11, Direction::NorthWest), vec![6, 1]);
+        assert_eq!(walk(1, Direction::North), vec![]);
+
+        let blocked: Vec<usize> = grid.walk_until(8, Direction::East, |n| *n >= 10).collect();
+        assert_eq!(blocked, vec![9, 10]);
+        let unblocked: Vec<usize> = grid.walk_until(8, Direction::North, |n| *n > 20).collect();
+        assert_eq!(unblocked, vec![4, 0]);
+    }
+
+    #[test]
+    fn can_take_subgrids_and_windows() {
+        /*
+         * 0123
+         * 4567
+         * 89ab
+         */
+        let grid = Grid::from_cells(4, (0..12).collect()).unwrap();
+        let sub = grid.subgrid(&Rect::new(1, 1, 3, 2)).unwrap();
+        assert_eq!(sub.cells(), &[5, 6, 7, 9, 10, 11]);
+        assert!(grid.subgrid(&Rect::new(2, 0, 3, 1)).is_none());
+
+        let sums: Vec<i32> = grid
+            .windows(3, 3)
+            .map(|rect| grid.cells_in(&rect).sum())
+            .collect();
+        assert_eq!(sums, vec![45, 54]);
+        assert_eq!(grid.windows(2, 2).count(), 6);
+        assert_eq!(grid.windows(5, 1).count(), 0);
+        assert_eq!(grid.windows(0, 2).count(), 0);
+        assert_eq!(grid.windows(2, 0).count(), 0);
+    }
+
+    #[test]
+    fn can_look_at_neighbourhoods() {
+        let grid = Grid::<char>::from_lines(["#.#", "##.", "..."]).unwrap();
+        let kernel = grid.kernel(0);
+        assert_eq!(kernel[..4], [None, None, None, None]);
+        assert_eq!(
+            kernel[4..],
+            [Some(&'#'), Some(&'.'), None, Some(&'#'), Some(&'#')]
+        );
+        assert_eq!(grid.kernel(4).iter().flatten().count(), 9);
+
+        assert_eq!(grid.count_neighbours(4, |c| *c == '#'), 3);
+        assert_eq!(grid.count_neighbours(8, |c| *c == '#'), 1);
+    }
+
+    #[test]
+    fn can_tilt() {
+        let mut grid = Grid::<char>::from_lines(["O.#.", ".O..", "O#O.", "...O"]).unwrap();
+        let round = |c: &char| *c == 'O';
+        let cube = |c: &char| *c == '#';
+
+        assert!(grid.tilt(Direction::North, round, cube));
+        assert_eq!(grid.render(|c| *c), "OO#O\nO.O.\n.#..\n....\n");
+        assert!(!grid.tilt(Direction::North, round, cube));
+
+        assert!(grid.tilt(Direction::East, round, cube));
+        assert_eq!(grid.render(|c| *c), "OO#O\n..OO\n.#..\n....\n");
+
+        assert!(grid.tilt(Direction::South, round, cube));
+        assert_eq!(grid.render(|c| *c), "..#.\n.O..\n.#.O\nO.OO\n");
+
+        assert!(grid.tilt(Direction::West, round, cube));
+        assert_eq!(grid.render(|c| *c), "..#.\nO...\n.#O.\nOOO.\n");
+    }
+
+    #[test]
+    fn can_count_diffs() {
+        assert_eq!(count_diffs(&[1, 2, 3], &[1, 0, 3]), 1);
+        assert_eq!(count_diffs(&[1, 2, 3], &[1, 2]), 1);
+        assert_eq!(count_diffs::<u8, _, _>(&[], &[]), 0);
+    }
+
+    #[test]
+    fn can_check_reflections() {
+        // 2023 day 13's first example, which reflects between columns 4 and
+        // 5, and (with one smudge) between rows 2 and 3.
+        let grid = Grid::<char>::from_lines([
+            "#.##..##.",
+            "..#.##.#.",
+            "##......#",
+            "##......#",
+            "..#.##.#.",
+            "..##..##.",
+            "#.#.##.#.",
+        ])
+        .unwrap();
+        let columns: Vec<usize> = (1..grid.width())
+            .filter(|x| grid.column_reflection_diffs(*x) == 0)
+            .collect();
+        assert_eq!(columns, vec![5]);
+
+        let rows: Vec<usize> = (1..grid.height())
+            .filter(|y| grid.row_reflection_diffs(*y) == 1)
+            .collect();
+        assert_eq!(rows, vec![3]);
+    }
+
+    #[test]
+    fn can_insert_rows_and_columns() {
+        let mut grid = Grid::<char>::from_lines(["ab", "cd"]).unwrap();
+        grid.insert_rows(1, 2, '.');
+        assert_eq!(grid.render(|c| *c), "ab\n..\n..\ncd\n");
+        grid.insert_columns(2, 1, '|');
+        grid.insert_columns(0, 1, '|');
+        assert_eq!(grid.render(|c| *c), "|ab|\n|..|\n|..|\n|cd|\n");
+        grid.insert_rows(4, 1, '-');
+        assert_eq!((grid.width(), grid.height()), (4, 5));
+        assert_eq!(grid.row(4), &['-'; 4]);
+    }
+
+    #[test]
+    fn can_expand_points() {
+        let grid = Grid::<char>::from_lines(["#..", "...", "..#"]).unwrap();
+        let mut inserted = grid.clone();
+        inserted.insert_rows(1, 2, '.');
+        inserted.insert_columns(1, 2, '.');
+
+        let expansion = Expansion::new(vec![1], vec![1], 2);
+        let expanded: Vec<Point> = grid
+            .positions_of(|c| *c == '#')
+            .map(|p| expansion.point(&Point::new(p.x, p.y)))
+            .collect();
+        let actual: Vec<Point> = inserted
+            .positions_of(|c| *c == '#')
+            .map(|p| Point::new(p.x, p.y))
+            .collect();
+        assert_eq!(expanded, actual);
+        assert_eq!(expansion.size(3, 3), (inserted.width(), inserted.height()));
+    }
+
+    #[test]
+    fn can_label_components() {
+        let grid = Grid::<char>::from_lines(["aa.b", "..bb", "c..b"]).unwrap();
+        let components = grid.components(Connectivity::Four, |c| *c != '.');
+        assert_eq!(components.sizes, vec![2, 4, 1]);
+        let labels = components
+            .labels
+            .render(|label| label.map_or('.', |l| (b'0' + l as u8) as char));
+        assert_eq!(labels, "00.1\n..11\n2..1\n");
+
+        // The a's and b's touch at a corner.
+        let diagonal = grid.components(Connectivity::Eight, |c| *c != '.');
+        assert_eq!(diagonal.sizes, vec![6, 1]);
+        let blanks = grid.components(Connectivity::Eight, |c| *c == '.');
+        assert_eq!(blanks.sizes, vec![5]);
+    }
+
+    #[test]
+    fn can_build_distance_field() {
+        let grid = Grid::<char>::from_lines(["A....", "..#..", "....B"]).unwrap();
+        let sources = [0, 14];
+        let field = grid.distance_field(&sources, Connectivity::Four, |c| *c != '#');
+        let render = |f: fn(&Reached) -> char| field.render(|cell| cell.as_ref().map_or('#', f));
+        assert_eq!(
+            render(|r| char::from_digit(r.distance as u32, 10).unwrap()),
+            "01232\n12#21\n23210\n"
+        );
+        assert_eq!(
+            render(|r| match r.tied {
+                true => '=',
+                false => (b'A' + r.source as u8) as char,
+            }),
+            "AAA=B\nAA#BB\nA=BBB\n"
+        );
+    }
+
+    #[test]
+    fn can_find_the_border() {
+        /*
+         * 0123
+         * 4567
+         * 89ab
+         */
+        let grid = Grid::new(4, 3);
+        assert_eq!(grid.border_indices(), vec![0, 1, 2, 3, 7, 11, 10, 9, 8, 4]);
+        let edges: Vec<usize> = grid.indices().filter(|idx| grid.is_on_edge(*idx)).collect();
+        assert_eq!(edges.len(), 10);
+        assert!(!grid.is_on_edge(5) && !grid.is_on_edge(6));
+
+        assert_eq!(Grid::new(3, 1).border_indices(), vec![0, 1, 2]);
+        assert_eq!(Grid::new(1, 3).border_indices(), vec![0, 1, 2]);
+        assert_eq!(Grid::new(1, 1).border_indices(), vec![0]);
+        assert_eq!(Grid::new(2, 2).border_indices(), vec![0, 1, 3, 2]);
+        assert!(Grid::new(0, 0).border_indices().is_empty());
+    }
+
+    #[test]
+    fn can_move_a_cursor() {
+        // Walks forward, turning right at each wall, until it leaves the grid.
+        let grid = Grid::<char>::from_lines(["..#.", "...#", "....", "...."]).unwrap();
+        let mut cursor = Cursor::new(Point::new(2, 3), Direction::North);
+        let mut visited = vec![cursor.position];
+        while let Some(next) = cursor.ahead(&grid) {
+            if grid[next] == '#' {
+                cursor.turn_right();
+            } else {
+                assert!(cursor.step(&grid));
+                visited.push(cursor.position);
+            }
+        }
+        assert_eq!(cursor, Cursor::new(Point::new(2, 3), Direction::South));
+        assert_eq!(visited.len(), 5);
+
+        assert!(!cursor.step(&grid));
+        cursor.turn_around();
+        cursor.turn_left();
+        assert_eq!(cursor.heading, Direction::West);
+    }
+
+    #[test]
+    fn can_change_cells() {
+        let mut grid = Grid::filled(3, 2, 0);
+        assert_eq!(grid.set(&Point::new(2, 1), 5), Some(0));
+        assert_eq!(grid.set(&Point::new(3, 1), 5), None);
+        grid[Point::new(0, 1)] += 1;
+        grid[0] = 7;
+        assert_eq!(grid.cells(), &[7, 0, 0, 1, 0, 5]);
+        assert_eq!(grid.map(|n| n * 2).cells(), &[14, 0, 0, 2, 0, 10]);
+    }
+
+    #[test]
+    fn can_iterate_over_positions() {
+        /*
+         * 0123
+         * 4567
+         */
+        let grid = Grid::new(4, 2);
+
+        assert_eq!(grid.len(), 8);
+
+        let positions: Vec<GridPosition> = grid.positions().collect();
+        assert_eq!(
+            positions,
+            [
+                GridPosition::new(0, 0, 0),
+                GridPosition::new(1, 1, 0),
+                GridPosition::new(2, 2, 0),
+                GridPosition::new(3, 3, 0),
+                GridPosition::new(4, 0, 1),
+                GridPosition::new(5, 1, 1),
+                GridPosition::new(6, 2, 1),
+                GridPosition::new(7, 3, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn can_iterate_over_positions_in_other_orders() {
+        /*
+         * ab
+         * cd
+         * ef
+         */
+        let grid = Grid::<char>::from_lines(["ab", "cd", "ef"]).unwrap();
+        let values = |positions: GridPositionIter<'_, char>| -> String {
+            positions.with_values().map(|(_, c)| *c).collect()
+        };
+        assert_eq!(values(grid.positions()), "abcdef");
+        assert_eq!(values(grid.positions_by_column()), "acebdf");
+
+        let mut backwards = grid.positions_by_column().rev();
+        assert_eq!(backwards.next(), Some(GridPosition::new(5, 1, 2)));
+        assert_eq!(backwards.next(), Some(GridPosition::new(3, 1, 1)));
+
+        // Both ends meet in the middle without repeating anything.
+        let mut positions = grid.positions();
+        assert_eq!(positions.len(), 6);
+        assert_eq!(positions.next().map(|p| p.index), Some(0));
+        assert_eq!(positions.next_back().map(|p| p.index), Some(5));
+        let middle: Vec<usize> = positions.map(|p| p.index).collect();
+        assert_eq!(middle, vec![1, 2, 3, 4]);
+    }
+}
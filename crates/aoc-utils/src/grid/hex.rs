@@ -0,0 +1,200 @@
+use std::{
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+use super::GridError;
+
+// A hex tile in axial coordinates. The third cube coordinate is implied by
+// q + r + s == 0, so it's worked out rather than stored.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Hex {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl Hex {
+    pub fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    pub fn s(&self) -> i64 {
+        -self.q - self.r
+    }
+
+    // Number of steps to get from one tile to the other.
+    pub fn distance(&self, other: &Self) -> u64 {
+        let d = *self - *other;
+        (d.q.unsigned_abs() + d.r.unsigned_abs() + d.s().unsigned_abs()) / 2
+    }
+
+    pub fn neighbours(&self) -> [Self; 6] {
+        OFFSETS.map(|(q, r)| *self + Self::new(q, r))
+    }
+
+    // Every tile at most `radius` steps away, this one included.
+    pub fn range(&self, radius: u64) -> impl Iterator<Item = Self> + '_ {
+        let n = radius as i64;
+        (-n..=n).flat_map(move |q| {
+            let rs = (-n).max(-q - n)..=n.min(-q + n);
+            rs.map(move |r| *self + Self::new(q, r))
+        })
+    }
+}
+
+impl Add for Hex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Hex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+// Going anticlockwise, starting from the one with q + 1, r + 0.
+const OFFSETS: [(i64, i64); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+// The same six moves get different names depending on whether the tiles
+// have a point or a flat edge at the top.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Pointy {
+    East,
+    NorthEast,
+    NorthWest,
+    West,
+    SouthWest,
+    SouthEast,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Flat {
+    SouthEast,
+    NorthEast,
+    North,
+    NorthWest,
+    SouthWest,
+    South,
+}
+
+impl Pointy {
+    pub fn offset(&self) -> Hex {
+        let (q, r) = OFFSETS[*self as usize];
+        Hex::new(q, r)
+    }
+
+    // Splits up a run of directions with nothing between them, like "nwwswee".
+    pub fn parse_run(s: &str) -> Result<Vec<Self>, GridError> {
+        let mut directions = vec![];
+        let mut rest = s;
+        while !rest.is_empty() {
+            let chars = if rest.starts_with(['n', 's']) { 2 } else { 1 };
+            let len = rest
+                .char_indices()
+                .nth(chars)
+                .map_or(rest.len(), |(i, _)| i);
+            let (direction, tail) = rest.split_at(len);
+            directions.push(direction.parse()?);
+            rest = tail;
+        }
+        Ok(directions)
+    }
+}
+
+impl Flat {
+    pub fn offset(&self) -> Hex {
+        let (q, r) = OFFSETS[*self as usize];
+        Hex::new(q, r)
+    }
+}
+
+impl FromStr for Pointy {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, GridError> {
+        let direction = match s {
+            "e" => Self::East,
+            "ne" => Self::NorthEast,
+            "nw" => Self::NorthWest,
+            "w" => Self::West,
+            "sw" => Self::SouthWest,
+            "se" => Self::SouthEast,
+            _ => return Err(GridError::BadDirection(s.to_string())),
+        };
+        Ok(direction)
+    }
+}
+
+impl FromStr for Flat {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, GridError> {
+        let direction = match s {
+            "se" => Self::SouthEast,
+            "ne" => Self::NorthEast,
+            "n" => Self::North,
+            "nw" => Self::NorthWest,
+            "sw" => Self::SouthWest,
+            "s" => Self::South,
+            _ => return Err(GridError::BadDirection(s.to_string())),
+        };
+        Ok(direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_measure_distance() {
+        let origin = Hex::default();
+        assert!(origin.neighbours().iter().all(|h| h.distance(&origin) == 1));
+        assert_eq!(Hex::new(3, -1).distance(&Hex::new(-1, 2)), 4);
+        assert_eq!(Hex::new(2, -3).s(), 1);
+
+        // 2017 day 11's examples.
+        let walk = |steps: &str| {
+            steps
+                .split(',')
+                .map(|s| s.parse::<Flat>().unwrap().offset())
+                .fold(origin, |hex, offset| hex + offset)
+                .distance(&origin)
+        };
+        assert_eq!(walk("ne,ne,ne"), 3);
+        assert_eq!(walk("ne,ne,sw,sw"), 0);
+        assert_eq!(walk("ne,ne,s,s"), 2);
+        assert_eq!(walk("se,sw,se,sw,sw"), 3);
+    }
+
+    #[test]
+    fn can_parse_runs() {
+        let run = Pointy::parse_run("nwwswee").unwrap();
+        let end = run.iter().fold(Hex::default(), |hex, d| hex + d.offset());
+        assert_eq!(end, Hex::default());
+        assert_eq!(
+            Pointy::parse_run("esew").unwrap(),
+            vec![Pointy::East, Pointy::SouthEast, Pointy::West]
+        );
+        assert_eq!(
+            Pointy::parse_run("en"),
+            Err(GridError::BadDirection("n".to_string()))
+        );
+        assert!(Pointy::parse_run("nö").is_err());
+    }
+
+    #[test]
+    fn can_iterate_over_range() {
+        let centre = Hex::new(2, -5);
+        let tiles: Vec<Hex> = centre.range(2).collect();
+        assert_eq!(tiles.len(), 19);
+        assert!(tiles.iter().all(|h| h.distance(&centre) <= 2));
+        assert_eq!(centre.range(0).collect::<Vec<_>>(), vec![centre]);
+    }
+}
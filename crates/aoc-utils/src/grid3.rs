@@ -0,0 +1,156 @@
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(&self, other: &Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    // The 6 points sharing a face with this one.
+    pub fn face_neighbours(&self) -> [Self; 6] {
+        [
+            Self::new(-1, 0, 0),
+            Self::new(1, 0, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, 0, -1),
+            Self::new(0, 0, 1),
+        ]
+        .map(|delta| *self + delta)
+    }
+
+    // The 26 points sharing a face, edge or corner with this one.
+    pub fn all_neighbours(&self) -> Vec<Self> {
+        (-1..=1)
+            .flat_map(|x| (-1..=1).map(move |y| (x, y)))
+            .flat_map(|(x, y)| (-1..=1).map(move |z| Self::new(x, y, z)))
+            .filter(|delta| *delta != Self::default())
+            .map(|delta| *self + delta)
+            .collect()
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+// A fixed-size block of cells covering 0..width, 0..height and 0..depth.
+// Points outside it simply aren't there, so lookups return None rather than
+// panicking.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Grid3<T> {
+    width: usize,
+    height: usize,
+    depth: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid3<T> {
+    pub fn filled(width: usize, height: usize, depth: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+            cells: vec![value; width * height * depth],
+        }
+    }
+}
+
+impl<T> Grid3<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        self.to_index(point).is_some()
+    }
+
+    pub fn get(&self, point: &Point3) -> Option<&T> {
+        self.to_index(point).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, point: &Point3) -> Option<&mut T> {
+        self.to_index(point).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn set(&mut self, point: &Point3, value: T) -> Option<T> {
+        self.get_mut(point)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point3> + '_ {
+        (0..self.depth).flat_map(move |z| {
+            (0..self.height).flat_map(move |y| {
+                (0..self.width).map(move |x| Point3::new(x as i64, y as i64, z as i64))
+            })
+        })
+    }
+
+    fn to_index(&self, point: &Point3) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        let z = usize::try_from(point.z).ok().filter(|z| *z < self.depth)?;
+        Some((z * self.height + y) * self.width + x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_get_neighbours() {
+        let origin = Point3::default();
+        let faces = origin.face_neighbours();
+        assert!(faces.iter().all(|p| p.manhattan(&origin) == 1));
+
+        let all = Point3::new(5, 5, 5).all_neighbours();
+        assert_eq!(all.len(), 26);
+        assert!(!all.contains(&Point3::new(5, 5, 5)));
+        assert!(all.contains(&Point3::new(4, 6, 4)));
+    }
+
+    #[test]
+    fn can_use_grid3() {
+        let mut grid = Grid3::filled(2, 3, 4, false);
+        assert_eq!(grid.points().count(), 24);
+        assert_eq!(grid.set(&Point3::new(1, 2, 3), true), Some(false));
+        assert_eq!(grid.set(&Point3::new(2, 0, 0), true), None);
+        assert_eq!(grid.get(&Point3::new(1, 2, 3)), Some(&true));
+        assert_eq!(grid.get(&Point3::new(0, -1, 0)), None);
+        assert_eq!(
+            grid.points().filter(|p| grid.get(p) == Some(&true)).count(),
+            1
+        );
+    }
+}
@@ -0,0 +1,13 @@
+pub mod automaton;
+pub mod bitset;
+pub mod counter;
+pub mod graph;
+pub mod grid;
+pub mod grid3;
+pub mod lru;
+pub mod maths;
+pub mod md5;
+pub mod rng;
+pub mod search;
+pub mod sparse_grid;
+pub mod string_scanner;
@@ -0,0 +1,255 @@
+/*
+ * def gcd(a, b):
+    """Return greatest common divisor using Euclid's Algorithm."""
+    while b:
+        a, b = b, a % b
+    return a
+
+def lcm(a, b):
+    """Return lowest common multiple."""
+    return a * b // gcd(a, b)
+
+def lcmm(*args):
+    """Return lcm of args."""
+    return reduce(lcm, args)
+[712, 157, 96, 591, 187, 100]
+>>> import math
+>>> math.lcm(*nums)
+1235403232800
+*/
+
+use std::ops::RangeInclusive;
+
+use num::{integer, Integer};
+
+pub fn gcd<T: Integer + Copy>(a: T, b: T) -> T {
+    integer::gcd(a, b)
+}
+
+pub fn gcd_all<T: Integer + Copy>(nums: &[T]) -> Option<T> {
+    let mut num_iter = nums.iter();
+
+    let mut result = *num_iter.next()?;
+
+    for x in num_iter {
+        result = gcd(result, *x);
+    }
+    Some(result)
+}
+
+pub fn lcm<T: Integer + Copy>(nums: &[T]) -> Option<T> {
+    let mut num_iter = nums.iter();
+
+    let mut result = *num_iter.next()?;
+
+    for x in num_iter {
+        result = integer::lcm(result, *x);
+    }
+    Some(result)
+}
+
+// The integers x for which ax² + bx + c > 0, which (as long as the parabola
+// opens downwards) form a single run between the roots. None if there aren't
+// any, or if a >= 0 and so the run wouldn't be bounded.
+pub fn quadratic_integer_solutions(a: i64, b: i64, c: i64) -> Option<RangeInclusive<i64>> {
+    if a >= 0 {
+        return None;
+    }
+    let (a, b, c) = (a as i128, b as i128, c as i128);
+    let f = |x: i128| a * x * x + b * x + c;
+
+    let discriminant = b * b - 4 * a * c;
+    if discriminant <= 0 {
+        return None;
+    }
+    // The float roots are only a starting point; nudge each end until it's
+    // exactly right.
+    let root = (discriminant as f64).sqrt();
+    let vertex = -b as f64 / (2 * a) as f64;
+    let half_width = root / (2 * a).abs() as f64;
+    let mut lo = (vertex - half_width).ceil() as i128;
+    let mut hi = (vertex + half_width).floor() as i128;
+    while f(lo) <= 0 && lo <= hi {
+        lo += 1;
+    }
+    while f(lo - 1) > 0 {
+        lo -= 1;
+    }
+    while f(hi) <= 0 && hi >= lo {
+        hi -= 1;
+    }
+    while f(hi + 1) > 0 {
+        hi += 1;
+    }
+
+    (lo <= hi).then_some(lo as i64..=hi as i64)
+}
+
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<T> {
+    Permutations {
+        items: items.to_vec(),
+        counters: vec![0; items.len()],
+        position: 0,
+        started: false,
+    }
+}
+
+// Heap's algorithm: each permutation differs from the previous one by a single swap.
+pub struct Permutations<T> {
+    items: Vec<T>,
+    counters: Vec<usize>,
+    position: usize,
+    started: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.items.clone());
+        }
+
+        while self.position < self.items.len() {
+            let i = self.position;
+            if self.counters[i] < i {
+                if i.is_multiple_of(2) {
+                    self.items.swap(0, i);
+                } else {
+                    self.items.swap(self.counters[i], i);
+                }
+                self.counters[i] += 1;
+                self.position = 0;
+                return Some(self.items.clone());
+            }
+            self.counters[i] = 0;
+            self.position += 1;
+        }
+
+        None
+    }
+}
+
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<T> {
+    let indices = if k <= items.len() {
+        Some((0..k).collect())
+    } else {
+        None
+    };
+    Combinations {
+        items: items.to_vec(),
+        indices,
+    }
+}
+
+// Yields the k-combinations in lexicographic order of their indices.
+pub struct Combinations<T> {
+    items: Vec<T>,
+    indices: Option<Vec<usize>>,
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let result = indices.iter().map(|i| self.items[*i].clone()).collect();
+
+        let n = self.items.len();
+        let k = indices.len();
+        match (0..k).rev().find(|i| indices[*i] != i + n - k) {
+            Some(i) => {
+                indices[i] += 1;
+                for j in (i + 1)..k {
+                    indices[j] = indices[j - 1] + 1;
+                }
+            }
+            None => {
+                self.indices = None;
+            }
+        }
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_calculate_lcm() {
+        let nums: Vec<u64> = vec![712, 157, 96, 591, 187, 100];
+        assert_eq!(lcm(&nums).unwrap(), 1235403232800);
+    }
+
+    #[test]
+    fn can_calculate_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-4i64, 6), 2);
+        assert_eq!(gcd(7u8, 0), 7);
+        assert_eq!(gcd_all(&[84u32, 126, 210]), Some(42));
+        assert_eq!(gcd_all::<u32>(&[]), None);
+    }
+
+    #[test]
+    fn can_find_quadratic_integer_solutions() {
+        // -x² + 7x - 9 > 0 for 2 <= x <= 5.
+        assert_eq!(quadratic_integer_solutions(-1, 7, -9), Some(2..=5));
+        // Roots at exactly 10 and 20 aren't included.
+        assert_eq!(quadratic_integer_solutions(-1, 30, -200), Some(11..=19));
+        assert_eq!(quadratic_integer_solutions(-2, 0, 1), Some(0..=0));
+        // Touches zero at x = 3 but is never above it.
+        assert_eq!(quadratic_integer_solutions(-1, 6, -9), None);
+        // Above zero only between 0.2 and 0.8.
+        assert_eq!(quadratic_integer_solutions(-25, 25, -4), None);
+        // Unbounded, or a line rather than a parabola.
+        assert_eq!(quadratic_integer_solutions(1, 0, -4), None);
+        assert_eq!(quadratic_integer_solutions(0, 1, 0), None);
+
+        let big = quadratic_integer_solutions(-1, 40_828_492, -233_101_111_101_487).unwrap();
+        assert_eq!(big.end() - big.start() + 1, 27_102_791);
+    }
+
+    #[test]
+    fn can_generate_permutations() {
+        let mut perms: Vec<Vec<u8>> = permutations(&[1, 2, 3]).collect();
+        assert_eq!(perms.len(), 6);
+        perms.sort();
+        assert_eq!(
+            perms,
+            vec![
+                vec![1, 2, 3],
+                vec![1, 3, 2],
+                vec![2, 1, 3],
+                vec![2, 3, 1],
+                vec![3, 1, 2],
+                vec![3, 2, 1],
+            ]
+        );
+
+        assert_eq!(permutations(&[0; 5]).count(), 120);
+        assert_eq!(permutations::<u8>(&[]).count(), 1);
+    }
+
+    #[test]
+    fn can_generate_combinations() {
+        let combos: Vec<Vec<char>> = combinations(&['a', 'b', 'c', 'd'], 2).collect();
+        assert_eq!(
+            combos,
+            vec![
+                vec!['a', 'b'],
+                vec!['a', 'c'],
+                vec!['a', 'd'],
+                vec!['b', 'c'],
+                vec!['b', 'd'],
+                vec!['c', 'd'],
+            ]
+        );
+
+        assert_eq!(combinations(&[1, 2, 3], 0).count(), 1);
+        assert_eq!(combinations(&[1, 2, 3], 3).count(), 1);
+        assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+    }
+}
@@ -0,0 +1,99 @@
+// SplitMix64: tiny, fast and good enough for generating puzzle inputs and for
+// randomised algorithms. The same seed always gives the same sequence.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Rng::below needs a non-zero bound");
+        self.next_u64() % n
+    }
+
+    // Both ends are included, so `between(0, u64::MAX)` can return anything.
+    pub fn between(&mut self, low: u64, high: u64) -> u64 {
+        assert!(
+            low <= high,
+            "Rng::between({}, {}) is an empty range",
+            low,
+            high
+        );
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.below(span),
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn choose<T: Copy>(&mut self, items: &[T]) -> T {
+        assert!(!items.is_empty(), "Rng::choose needs at least one item");
+        items[self.below(items.len() as u64) as usize]
+    }
+
+    // Fisher-Yates.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        let mut c = Rng::new(43);
+        let first: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..10).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(first, (0..10).map(|_| c.next_u64()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_pick_within_bounds() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let n = rng.between(3, 5);
+            assert!((3..=5).contains(&n));
+        }
+        assert_eq!(rng.choose(&['x']), 'x');
+        assert_eq!(rng.between(9, 9), 9);
+        rng.between(0, u64::MAX);
+        assert!(rng.between(u64::MAX - 1, u64::MAX) >= u64::MAX - 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn empty_ranges_are_rejected() {
+        Rng::new(7).between(5, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one item")]
+    fn cant_choose_from_nothing() {
+        Rng::new(7).choose::<u8>(&[]);
+    }
+
+    #[test]
+    fn shuffling_keeps_every_item() {
+        let mut items: Vec<u32> = (0..20).collect();
+        Rng::new(1).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
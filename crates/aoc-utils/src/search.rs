@@ -0,0 +1,102 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+// The cheapest route from `start` to any state satisfying `is_goal`, as the
+// states visited (start and goal included) and the total cost. `neighbours`
+// gives the states reachable in one step along with the cost of that step.
+pub fn dijkstra<S, N, I, G>(start: S, mut neighbours: N, mut is_goal: G) -> Option<(Vec<S>, u64)>
+where
+    S: Clone + Eq + Hash,
+    N: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+    G: FnMut(&S) -> bool,
+{
+    // The queue holds indexes into `queued` rather than the states themselves,
+    // so states don't need to be ordered. Equal costs come out oldest first.
+    let mut best = HashMap::from([(start.clone(), 0)]);
+    let mut came_from: HashMap<S, S> = HashMap::new();
+    let mut queued = vec![start];
+    let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        let state = queued[index].clone();
+        if is_goal(&state) {
+            let mut path = vec![state];
+            while let Some(previous) = came_from.get(path.last().unwrap()) {
+                path.push(previous.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        if best.get(&state).is_some_and(|best| cost > *best) {
+            continue;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if best.get(&next).is_none_or(|best| next_cost < *best) {
+                best.insert(next.clone(), next_cost);
+                came_from.insert(next.clone(), state.clone());
+                queue.push(Reverse((next_cost, queued.len())));
+                queued.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_find_cheapest_route() {
+        // The direct edge from 0 to 3 is dearer than going the long way round.
+        let edges = |n: &u8| match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        };
+        assert_eq!(dijkstra(0, edges, |n| *n == 3), Some((vec![0, 1, 2, 3], 6)));
+        assert_eq!(dijkstra(0, edges, |n| *n == 0), Some((vec![0], 0)));
+        assert_eq!(dijkstra(1, edges, |n| *n == 0), None);
+    }
+
+    #[test]
+    fn can_search_over_richer_states() {
+        // Walk along a line, but never take more than two steps the same way.
+        // States are (position, steps taken in the current direction, direction).
+        let moves = |(pos, run, dir): &(i32, u8, i32)| {
+            [-1, 1]
+                .into_iter()
+                .filter(move |d| *d != *dir || *run < 2)
+                .map(move |d| {
+                    let run = if d == *dir { run + 1 } else { 1 };
+                    ((pos + d, run, d), 1)
+                })
+                .collect::<Vec<_>>()
+        };
+        let (path, cost) = dijkstra((0, 0, 0), moves, |(pos, _, _)| *pos == 3).unwrap();
+        assert_eq!(cost, 5);
+        assert_eq!(path.len(), 6);
+    }
+
+    #[test]
+    fn states_dont_need_an_order() {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        struct Room(&'static str);
+
+        let doors = |room: &Room| match room.0 {
+            "hall" => vec![(Room("kitchen"), 2), (Room("study"), 1)],
+            "study" => vec![(Room("kitchen"), 3)],
+            _ => vec![],
+        };
+        let (path, cost) = dijkstra(Room("hall"), doors, |room| room.0 == "kitchen").unwrap();
+        assert_eq!(path, vec![Room("hall"), Room("kitchen")]);
+        assert_eq!(cost, 2);
+    }
+}
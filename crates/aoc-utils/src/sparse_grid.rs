@@ -0,0 +1,150 @@
+use std::collections::{hash_map, HashMap};
+
+pub type Coord = (i64, i64);
+
+// The smallest rectangle holding every cell, inclusive at both ends.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Bounds {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Bounds {
+    pub fn width(&self) -> u64 {
+        self.min.0.abs_diff(self.max.0) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min.1.abs_diff(self.max.1) + 1
+    }
+
+    pub fn contains(&self, (x, y): Coord) -> bool {
+        (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+    }
+}
+
+// A grid with no fixed size, holding only the cells that have been set.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SparseGrid<T>(HashMap<Coord, T>);
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, coord: Coord, value: T) -> Option<T> {
+        self.0.insert(coord, value)
+    }
+
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        self.0.remove(&coord)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.0.get(&coord)
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.0.get_mut(&coord)
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.0.contains_key(&coord)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // In no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Coord, T> {
+        self.0.iter()
+    }
+
+    pub fn bounds(&self) -> Option<Bounds> {
+        let mut coords = self.0.keys();
+        let first = *coords.next()?;
+        let bounds = coords.fold(
+            Bounds {
+                min: first,
+                max: first,
+            },
+            |b, (x, y)| Bounds {
+                min: (b.min.0.min(*x), b.min.1.min(*y)),
+                max: (b.max.0.max(*x), b.max.1.max(*y)),
+            },
+        );
+        Some(bounds)
+    }
+
+    // Draws the bounding box, one line per row (top row is the smallest y),
+    // with `empty` for cells that aren't set.
+    pub fn render<F: FnMut(&T) -> char>(&self, empty: char, mut f: F) -> String {
+        let Some(Bounds { min, max }) = self.bounds() else {
+            return String::new();
+        };
+        let mut s = String::new();
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                s.push(self.get((x, y)).map_or(empty, &mut f));
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_find_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+
+        grid.insert((3, -2), 'a');
+        grid.insert((-1, 4), 'b');
+        let bounds = grid.bounds().unwrap();
+        assert_eq!(bounds.min, (-1, -2));
+        assert_eq!(bounds.max, (3, 4));
+        assert_eq!((bounds.width(), bounds.height()), (5, 7));
+        assert!(bounds.contains((0, 0)));
+        assert!(!bounds.contains((4, 0)));
+    }
+
+    #[test]
+    fn can_render() {
+        let grid: SparseGrid<u8> = [((-1, -1), 1), ((1, 0), 2)].into_iter().collect();
+        assert_eq!(grid.render('.', |n| (b'0' + n) as char), "1..\n..2\n");
+        assert_eq!(SparseGrid::<u8>::new().render('.', |_| '#'), "");
+    }
+
+    #[test]
+    fn can_change_cells() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(grid.insert((i64::MIN, i64::MAX), 1), None);
+        assert_eq!(grid.insert((i64::MIN, i64::MAX), 2), Some(1));
+        *grid.get_mut((i64::MIN, i64::MAX)).unwrap() += 1;
+        assert_eq!(grid.get((i64::MIN, i64::MAX)), Some(&3));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.remove((i64::MIN, i64::MAX)), Some(3));
+        assert!(grid.is_empty());
+    }
+}
@@ -15,6 +15,16 @@ pub enum StringScannerError {
     UnexpectedChar { expected: char, position: usize },
 }
 
+impl StringScannerError {
+    pub fn position(&self) -> usize {
+        match self {
+            Self::UnexpectedString { position, .. }
+            | Self::NotAUint { position, .. }
+            | Self::UnexpectedChar { position, .. } => *position,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct StringScanner {
     current_position: usize,
@@ -154,6 +164,46 @@ impl StringScanner {
     }
 }
 
+impl StringScanner {
+    pub fn peek_token<'t, T>(&self, tokens: &'t [(String, T)]) -> Option<&'t T> {
+        tokens
+            .iter()
+            .find(|(token, _)| self.peek_string(token))
+            .map(|(_, value)| value)
+    }
+}
+
+// Yields the value of every token found in the source, including overlapping
+// ones (so "twone" gives both two and one). Earlier tokens win ties.
+pub struct TokenIter<'t, T> {
+    scanner: StringScanner,
+    tokens: &'t [(String, T)],
+}
+
+impl<'t, T> TokenIter<'t, T> {
+    pub fn new(source: &str, tokens: &'t [(String, T)]) -> Self {
+        Self {
+            scanner: StringScanner::new(source),
+            tokens,
+        }
+    }
+}
+
+impl<'t, T> Iterator for TokenIter<'t, T> {
+    type Item = &'t T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.scanner.is_finished() {
+            let found = self.scanner.peek_token(self.tokens);
+            self.scanner.advance();
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -196,4 +246,15 @@ mod test {
         let mut scanner = StringScanner::new("20 January");
         assert_eq!(scanner.expect_uint::<u32>().unwrap(), 20);
     }
+
+    #[test]
+    fn test_token_iter() {
+        let tokens = vec![
+            ("one".to_string(), 1),
+            ("two".to_string(), 2),
+            ("e".to_string(), 0),
+        ];
+        let found: Vec<&u32> = TokenIter::new("xtwone", &tokens).collect();
+        assert_eq!(found, vec![&2, &1, &0]);
+    }
 }
@@ -0,0 +1,90 @@
+use crate::core::{Answer, CoreError, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(Elevator {
+        floor: 0,
+        steps: 0,
+        stop_at_basement: false,
+    })
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(Elevator {
+        floor: 0,
+        steps: 0,
+        stop_at_basement: true,
+    })
+}
+
+#[derive(Debug)]
+struct Elevator {
+    floor: i64,
+    steps: usize,
+    stop_at_basement: bool,
+}
+
+impl Elevator {
+    fn in_basement(&self) -> bool {
+        self.floor < 0
+    }
+}
+
+impl Solver for Elevator {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        for c in line.chars() {
+            if self.stop_at_basement && self.in_basement() {
+                break;
+            }
+            self.floor += match c {
+                '(' => 1,
+                ')' => -1,
+                _ => return Err(CoreError::General(format!("Unknown instruction {:?}", c))),
+            };
+            self.steps += 1;
+        }
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        match self.stop_at_basement {
+            false => Ok(self.floor.into()),
+            true if self.in_basement() => Ok(self.steps.into()),
+            true => Err(CoreError::general("Santa never reaches the basement")),
+        }
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Not Quite Lisp")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Following parentheses up and down the floors of a building")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn solve(mut solver: Box<dyn Solver>, line: &str) -> Result<Answer> {
+        solver.handle_line(line)?;
+        solver.extract_solution()
+    }
+
+    #[test]
+    fn can_find_the_floor() -> Result<()> {
+        assert_eq!(solve(part_1(), "(())")?, "0");
+        assert_eq!(solve(part_1(), "))(((((")?, "3");
+        assert_eq!(solve(part_1(), ")())())")?, "-3");
+        assert!(solve(part_1(), "(x)").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn can_find_the_basement() -> Result<()> {
+        assert_eq!(solve(part_2(), ")")?, "1");
+        assert_eq!(solve(part_2(), "()())(((")?, "5");
+        assert!(solve(part_2(), "((").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::core::{Answer, CoreError, Puzzle, PuzzlePart, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Presents::solver(PuzzlePart::One)
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Presents::solver(PuzzlePart::Two)
+}
+
+struct Presents;
+
+impl Puzzle for Presents {
+    type Parsed = Vec<Present>;
+
+    fn parse_line(presents: &mut Vec<Present>, line: &str) -> Result<()> {
+        presents.push(Present::from_string(line)?);
+        Ok(())
+    }
+
+    fn part_1(presents: &Vec<Present>) -> Result<Answer> {
+        Ok(presents.iter().map(Present::paper).sum::<u64>().into())
+    }
+
+    fn part_2(presents: &Vec<Present>) -> Result<Answer> {
+        Ok(presents.iter().map(Present::ribbon).sum::<u64>().into())
+    }
+
+    fn title() -> Option<&'static str> {
+        Some("I Was Told There Would Be No Math")
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("Wrapping paper and ribbon needed for every present")
+    }
+}
+
+// Dimensions are kept sorted, smallest first.
+#[derive(Debug, Eq, PartialEq)]
+struct Present([u64; 3]);
+
+impl Present {
+    fn from_string(line: &str) -> Result<Self> {
+        let mut dimensions = [0; 3];
+        let mut parts = line.split('x');
+        for dimension in dimensions.iter_mut() {
+            let part = parts
+                .next()
+                .ok_or_else(|| CoreError::general("Expected LxWxH"))?;
+            *dimension = part.parse()?;
+        }
+        if parts.next().is_some() {
+            return Err(CoreError::general("Expected LxWxH"));
+        }
+        dimensions.sort();
+        Ok(Self(dimensions))
+    }
+
+    fn paper(&self) -> u64 {
+        let [a, b, c] = self.0;
+        2 * (a * b + b * c + c * a) + a * b
+    }
+
+    fn ribbon(&self) -> u64 {
+        let [a, b, c] = self.0;
+        2 * (a + b) + a * b * c
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_parse_presents() -> Result<()> {
+        assert_eq!(Present::from_string("4x2x3")?, Present([2, 3, 4]));
+        assert!(Present::from_string("4x2").is_err());
+        assert!(Present::from_string("4x2x3x1").is_err());
+        assert!(Present::from_string("4xax3").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn can_measure_paper_and_ribbon() {
+        assert_eq!(Present([2, 3, 4]).paper(), 58);
+        assert_eq!(Present([1, 1, 10]).paper(), 43);
+        assert_eq!(Present([2, 3, 4]).ribbon(), 34);
+        assert_eq!(Present([1, 1, 10]).ribbon(), 14);
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::core::{Answer, CoreError, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(Delivery::new(1))
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(Delivery::new(2))
+}
+
+// Santa (and, in part 2, Robo-Santa) take turns following the directions.
+#[derive(Debug)]
+struct Delivery {
+    santas: Vec<(i64, i64)>,
+    turn: usize,
+    visited: HashSet<(i64, i64)>,
+}
+
+impl Delivery {
+    fn new(num_santas: usize) -> Self {
+        Self {
+            santas: vec![(0, 0); num_santas],
+            turn: 0,
+            visited: HashSet::from([(0, 0)]),
+        }
+    }
+}
+
+impl Solver for Delivery {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        for c in line.chars() {
+            let (dx, dy) = match c {
+                '^' => (0, -1),
+                'v' => (0, 1),
+                '<' => (-1, 0),
+                '>' => (1, 0),
+                _ => return Err(CoreError::General(format!("Unknown direction {:?}", c))),
+            };
+            let santa = &mut self.santas[self.turn];
+            *santa = (santa.0 + dx, santa.1 + dy);
+            self.visited.insert(*santa);
+            self.turn = (self.turn + 1) % self.santas.len();
+        }
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.visited.len().into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Perfectly Spherical Houses in a Vacuum")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Houses that get at least one present")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn houses(num_santas: usize, line: &str) -> usize {
+        let mut delivery = Delivery::new(num_santas);
+        delivery.handle_line(line).unwrap();
+        delivery.visited.len()
+    }
+
+    #[test]
+    fn can_count_houses() {
+        assert_eq!(houses(1, ">"), 2);
+        assert_eq!(houses(1, "^>v<"), 4);
+        assert_eq!(houses(1, "^v^v^v^v^v"), 2);
+        assert!(Delivery::new(1).handle_line("^x").is_err());
+    }
+
+    #[test]
+    fn robo_santa_takes_turns() {
+        assert_eq!(houses(2, "^v"), 3);
+        assert_eq!(houses(2, "^>v<"), 3);
+        assert_eq!(houses(2, "^v^v^v^v^v"), 11);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    core::{Answer, Cancellation, CoreError, Cost, Result, Solver},
+    md5::md5,
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(Miner::new(5))
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(Miner::new(6))
+}
+
+// How often to check for Ctrl-C.
+const CANCELLATION_STEP: u64 = 1 << 16;
+
+#[derive(Debug)]
+struct Miner {
+    secret: String,
+    zeros: usize,
+    cancellation: Cancellation,
+}
+
+impl Miner {
+    fn new(zeros: usize) -> Self {
+        Self {
+            secret: String::new(),
+            zeros,
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    fn mine(&self) -> Result<u64> {
+        for n in 1u64.. {
+            if n.is_multiple_of(CANCELLATION_STEP) && self.cancellation.is_cancelled() {
+                return Err(CoreError::Interrupted(format!("{} hashes", n)));
+            }
+            let digest = md5(format!("{}{}", self.secret, n).as_bytes());
+            if starts_with_zeros(&digest, self.zeros) {
+                return Ok(n);
+            }
+        }
+        unreachable!()
+    }
+}
+
+// Whether the digest, in hex, starts with this many zeros.
+fn starts_with_zeros(digest: &[u8], zeros: usize) -> bool {
+    let whole_bytes = &digest[..zeros / 2];
+    whole_bytes.iter().all(|&b| b == 0) && (zeros.is_multiple_of(2) || digest[zeros / 2] < 0x10)
+}
+
+impl Solver for Miner {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        self.secret.push_str(line.trim());
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.mine()?.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("The Ideal Stocking Stuffer")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Mining AdventCoins: MD5 hashes that start with zeros")
+    }
+
+    fn cost(&self) -> Cost {
+        match self.zeros {
+            0..=5 => Cost::Fast,
+            _ => Cost::Slow,
+        }
+    }
+
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::md5::md5_hex;
+
+    #[test]
+    fn can_count_leading_zeros() {
+        let digest = md5(b"abcdef609043");
+        assert!(md5_hex(b"abcdef609043").starts_with("000001dbbfa"));
+        assert!(starts_with_zeros(&digest, 5));
+        assert!(!starts_with_zeros(&digest, 6));
+        assert!(starts_with_zeros(&[0, 0, 0, 0x0f], 7));
+        assert!(!starts_with_zeros(&[0, 0, 0, 0x10], 7));
+    }
+
+    // The real examples take too long to mine without optimisations.
+    #[test]
+    fn can_mine_a_few_zeros() -> Result<()> {
+        let mut miner = Miner::new(2);
+        miner.handle_line("abcdef")?;
+        let n = miner.mine()?;
+        assert!(md5_hex(format!("abcdef{}", n).as_bytes()).starts_with("00"));
+        assert!((1..n).all(|m| !md5_hex(format!("abcdef{}", m).as_bytes()).starts_with("00")));
+        Ok(())
+    }
+
+    #[test]
+    fn can_be_interrupted() {
+        let miner = Miner::new(32);
+        miner.cancellation.cancel();
+        let err = miner.mine().unwrap_err();
+        assert!(matches!(err, CoreError::Interrupted(_)));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core::{Answer, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(NiceStrings(0, is_nice))
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(NiceStrings(0, is_nicer))
+}
+
+struct NiceStrings(usize, fn(&[u8]) -> bool);
+
+impl Solver for NiceStrings {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        if self.1(line.as_bytes()) {
+            self.0 += 1;
+        }
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.0.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Doesn't He Have Intern-Elves For This?")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Strings that are naughty or nice")
+    }
+}
+
+fn is_nice(s: &[u8]) -> bool {
+    let vowels = s.iter().filter(|c| b"aeiou".contains(c)).count();
+    let doubled = s.windows(2).any(|w| w[0] == w[1]);
+    let forbidden = [b"ab", b"cd", b"pq", b"xy"];
+    let clean = s.windows(2).all(|w| !forbidden.iter().any(|f| w == *f));
+    vowels >= 3 && doubled && clean
+}
+
+fn is_nicer(s: &[u8]) -> bool {
+    // A pair that appears again later on, without overlapping.
+    let repeated_pair = (0..s.len().saturating_sub(1))
+        .any(|i| s[i + 2..].windows(2).any(|w| w == &s[i..i + 2]));
+    let sandwich = s.windows(3).any(|w| w[0] == w[2]);
+    repeated_pair && sandwich
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_spot_nice_strings() {
+        assert!(is_nice(b"ugknbfddgicrmopn"));
+        assert!(is_nice(b"aaa"));
+        assert!(!is_nice(b"jchzalrnumimnmhp"));
+        assert!(!is_nice(b"haegwjzuvuyypxyu"));
+        assert!(!is_nice(b"dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn can_spot_nicer_strings() {
+        assert!(is_nicer(b"qjhvhtzxzqqjkmpb"));
+        assert!(is_nicer(b"xxyxx"));
+        assert!(!is_nicer(b"uurcxstgmygtbstg"));
+        assert!(!is_nicer(b"ieodomkazucvgmuy"));
+        assert!(!is_nicer(b"aaa"));
+        assert!(!is_nicer(b""));
+    }
+}
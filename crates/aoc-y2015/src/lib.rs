@@ -0,0 +1,5 @@
+use aoc_core::core;
+use aoc_utils::md5;
+
+// Generated by build_days.rs from the dNN.rs files in this directory.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
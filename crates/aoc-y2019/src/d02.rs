@@ -0,0 +1,99 @@
+use crate::{
+    core::{Answer, Cancellation, CoreError, Result, Solver},
+    intcode::Machine,
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(GravityAssist::new(None))
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(GravityAssist::new(Some(19690720)))
+}
+
+#[derive(Debug)]
+struct GravityAssist {
+    program: String,
+    find_output: Option<i64>,
+    cancellation: Cancellation,
+}
+
+impl GravityAssist {
+    fn new(find_output: Option<i64>) -> Self {
+        Self {
+            program: String::new(),
+            find_output,
+            cancellation: Cancellation::default(),
+        }
+    }
+
+    // The program is parsed once, and each run starts from a copy of it.
+    fn machine(&self) -> Result<Machine> {
+        let mut machine: Machine = self.program.parse()?;
+        machine.set_cancellation(self.cancellation.clone());
+        Ok(machine)
+    }
+}
+
+fn run(program: &Machine, noun: i64, verb: i64) -> Result<i64> {
+    let mut machine = program.clone();
+    machine.write(1, noun);
+    machine.write(2, verb);
+    machine.run()?;
+    Ok(machine.read(0))
+}
+
+impl Solver for GravityAssist {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        self.program.push_str(line);
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        let program = self.machine()?;
+        let Some(target) = self.find_output else {
+            return Ok(run(&program, 12, 2)?.into());
+        };
+        for noun in 0..100 {
+            for verb in 0..100 {
+                if run(&program, noun, verb)? == target {
+                    return Ok((100 * noun + verb).into());
+                }
+            }
+        }
+        let msg = format!("No noun and verb give {}", target);
+        Err(CoreError::General(msg))
+    }
+
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("1202 Program Alarm")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Restoring the gravity assist program's state")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Adds the noun and verb together.
+    const PROGRAM: &str = "1101,0,0,0,99";
+
+    #[test]
+    fn can_search_for_noun_and_verb() -> Result<()> {
+        let mut solver = GravityAssist::new(Some(50));
+        solver.handle_line(PROGRAM)?;
+        assert_eq!(run(&solver.machine()?, 12, 2)?, 14);
+        assert_eq!(solver.extract_solution()?, "50");
+
+        solver.find_output = Some(500);
+        assert!(solver.extract_solution().is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use crate::{
+    core::{Answer, Cancellation, CoreError, Result, Solver},
+    intcode::{Machine, State},
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(Diagnostic {
+        program: String::new(),
+        system_id: 1,
+        cancellation: Cancellation::default(),
+    })
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(Diagnostic {
+        program: String::new(),
+        system_id: 5,
+        cancellation: Cancellation::default(),
+    })
+}
+
+#[derive(Debug)]
+struct Diagnostic {
+    program: String,
+    system_id: i64,
+    cancellation: Cancellation,
+}
+
+impl Diagnostic {
+    // Every output but the last is a test result, which should be zero.
+    fn run(&self) -> Result<i64> {
+        let mut machine: Machine = self.program.parse()?;
+        machine.set_cancellation(self.cancellation.clone());
+        machine.push_input(self.system_id);
+        if machine.run()? == State::NeedsInput {
+            return Err(CoreError::general("The program wants more input"));
+        }
+        let output = machine.take_output();
+        let Some((code, tests)) = output.split_last() else {
+            return Err(CoreError::general("The program didn't output anything"));
+        };
+        if let Some(failed) = tests.iter().position(|&result| result != 0) {
+            let msg = format!("Test {} failed with {}", failed + 1, tests[failed]);
+            return Err(CoreError::General(msg));
+        }
+        Ok(*code)
+    }
+}
+
+impl Solver for Diagnostic {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        self.program.push_str(line);
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.run()?.into())
+    }
+
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Sunny with a Chance of Asteroids")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Diagnostic codes from the thermal environment supervision terminal")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn diagnose(program: &str, system_id: i64) -> Result<i64> {
+        let diagnostic = Diagnostic {
+            program: program.to_string(),
+            system_id,
+            cancellation: Cancellation::default(),
+        };
+        diagnostic.run()
+    }
+
+    #[test]
+    fn reports_the_diagnostic_code() -> Result<()> {
+        let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,\
+            1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,\
+            999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+        assert_eq!(diagnose(program, 7)?, 999);
+        assert_eq!(diagnose(program, 8)?, 1000);
+        assert_eq!(diagnose(program, 9)?, 1001);
+        Ok(())
+    }
+
+    #[test]
+    fn checks_the_tests_passed() {
+        assert_eq!(diagnose("104,0,104,0,104,7,99", 1).unwrap(), 7);
+        assert!(diagnose("104,0,104,3,104,7,99", 1).is_err());
+        assert!(diagnose("99", 1).is_err());
+        assert!(diagnose("3,0,3,0,99", 1).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use crate::core::{Cancellation, CoreError, Result};
+
+// Memory up to here is a plain Vec; anything written further out goes in a
+// map, so a stray huge address can't ask for terabytes.
+const DENSE_MEMORY: usize = 1 << 20;
+// How many instructions run between checks for cancellation.
+const CHECK_EVERY: u64 = 1 << 12;
+
+// An Intcode computer. Input and output are queues, so machines can be run a
+// bit at a time and wired up to each other: `run` stops either when the
+// program halts or when it needs input that hasn't been given yet.
+#[derive(Debug, Clone, Default)]
+pub struct Machine {
+    memory: Vec<i64>,
+    sparse: HashMap<usize, i64>,
+    ip: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+    output: VecDeque<i64>,
+    cancellation: Cancellation,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum State {
+    Halted,
+    NeedsInput,
+}
+
+impl FromStr for Machine {
+    type Err = CoreError;
+
+    fn from_str(program: &str) -> Result<Self> {
+        let memory = program
+            .trim()
+            .split(',')
+            .map(|n| Ok(n.trim().parse()?))
+            .collect::<Result<_>>()?;
+        Ok(Self::new(memory))
+    }
+}
+
+impl Machine {
+    pub fn new(memory: Vec<i64>) -> Self {
+        Self {
+            memory,
+            ..Default::default()
+        }
+    }
+
+    // Memory is unbounded; anything never written reads as zero.
+    pub fn read(&self, address: usize) -> i64 {
+        match self.memory.get(address) {
+            Some(value) => *value,
+            None => self.sparse.get(&address).copied().unwrap_or(0),
+        }
+    }
+
+    pub fn write(&mut self, address: usize, value: i64) {
+        if address < self.memory.len() {
+            self.memory[address] = value;
+        } else if address < DENSE_MEMORY {
+            self.memory.resize(address + 1, 0);
+            self.memory[address] = value;
+        } else {
+            self.sparse.insert(address, value);
+        }
+    }
+
+    // `run` checks this every so often, so a program that loops for ever can
+    // still be stopped.
+    pub fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    pub fn pop_output(&mut self) -> Option<i64> {
+        self.output.pop_front()
+    }
+
+    pub fn take_output(&mut self) -> Vec<i64> {
+        self.output.drain(..).collect()
+    }
+
+    pub fn run(&mut self) -> Result<State> {
+        let mut steps: u64 = 0;
+        loop {
+            steps += 1;
+            if steps.is_multiple_of(CHECK_EVERY) && self.cancellation.is_cancelled() {
+                return Err(CoreError::Interrupted(format!("{} instructions", steps)));
+            }
+            let instruction = self.read(self.ip);
+            let opcode = instruction % 100;
+            match opcode {
+                1 | 2 | 7 | 8 => {
+                    let (a, b) = (self.param(instruction, 1)?, self.param(instruction, 2)?);
+                    let value = match opcode {
+                        1 => a.checked_add(b).ok_or_else(|| self.overflow())?,
+                        2 => a.checked_mul(b).ok_or_else(|| self.overflow())?,
+                        7 => (a < b) as i64,
+                        _ => (a == b) as i64,
+                    };
+                    let address = self.address(instruction, 3)?;
+                    self.write(address, value);
+                    self.ip += 4;
+                }
+                3 => {
+                    let Some(value) = self.input.pop_front() else {
+                        return Ok(State::NeedsInput);
+                    };
+                    let address = self.address(instruction, 1)?;
+                    self.write(address, value);
+                    self.ip += 2;
+                }
+                4 => {
+                    let value = self.param(instruction, 1)?;
+                    self.output.push_back(value);
+                    self.ip += 2;
+                }
+                5 | 6 => {
+                    let (test, target) = (self.param(instruction, 1)?, self.param(instruction, 2)?);
+                    if (test != 0) == (opcode == 5) {
+                        self.ip = to_address(target)?;
+                    } else {
+                        self.ip += 3;
+                    }
+                }
+                9 => {
+                    let offset = self.param(instruction, 1)?;
+                    self.relative_base = self
+                        .relative_base
+                        .checked_add(offset)
+                        .ok_or_else(|| self.overflow())?;
+                    self.ip += 2;
+                }
+                99 => return Ok(State::Halted),
+                _ => {
+                    let msg = format!("Unknown opcode {} at {}", instruction, self.ip);
+                    return Err(CoreError::General(msg));
+                }
+            }
+        }
+    }
+
+    fn overflow(&self) -> CoreError {
+        CoreError::General(format!("Overflow at {}", self.ip))
+    }
+
+    // The value of the nth parameter, according to its mode.
+    fn param(&self, instruction: i64, n: u32) -> Result<i64> {
+        match mode(instruction, n) {
+            1 => Ok(self.read(self.ip + n as usize)),
+            _ => Ok(self.read(self.address(instruction, n)?)),
+        }
+    }
+
+    // The address the nth parameter refers to, for reading or writing.
+    fn address(&self, instruction: i64, n: u32) -> Result<usize> {
+        let raw = self.read(self.ip + n as usize);
+        match mode(instruction, n) {
+            0 => to_address(raw),
+            2 => match self.relative_base.checked_add(raw) {
+                Some(address) => to_address(address),
+                None => Err(self.overflow()),
+            },
+            m => {
+                let msg = format!("Bad parameter mode {} at {}", m, self.ip);
+                Err(CoreError::General(msg))
+            }
+        }
+    }
+}
+
+fn mode(instruction: i64, n: u32) -> i64 {
+    instruction / 10_i64.pow(n + 1) % 10
+}
+
+fn to_address(value: i64) -> Result<usize> {
+    usize::try_from(value).map_err(|_| CoreError::General(format!("Negative address {}", value)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(program: &str, input: &[i64]) -> Result<Vec<i64>> {
+        let mut machine: Machine = program.parse()?;
+        for value in input {
+            machine.push_input(*value);
+        }
+        assert_eq!(machine.run()?, State::Halted);
+        Ok(machine.take_output())
+    }
+
+    #[test]
+    fn can_add_and_multiply() -> Result<()> {
+        let mut machine: Machine = "1,9,10,3,2,3,11,0,99,30,40,50".parse()?;
+        machine.run()?;
+        assert_eq!(machine.read(0), 3500);
+
+        let mut machine: Machine = "1002,4,3,4,33".parse()?;
+        machine.run()?;
+        assert_eq!(machine.read(4), 99);
+        Ok(())
+    }
+
+    #[test]
+    fn can_compare_and_jump() -> Result<()> {
+        let equal_to_8 = "3,9,8,9,10,9,4,9,99,-1,8";
+        assert_eq!(run(equal_to_8, &[8])?, vec![1]);
+        assert_eq!(run(equal_to_8, &[7])?, vec![0]);
+
+        let less_than_8 = "3,3,1107,-1,8,3,4,3,99";
+        assert_eq!(run(less_than_8, &[7])?, vec![1]);
+
+        let non_zero = "3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9";
+        assert_eq!(run(non_zero, &[0])?, vec![0]);
+        assert_eq!(run(non_zero, &[5])?, vec![1]);
+        Ok(())
+    }
+
+    #[test]
+    fn can_use_relative_addresses() -> Result<()> {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected: Vec<i64> = quine.split(',').map(|n| n.parse().unwrap()).collect();
+        assert_eq!(run(quine, &[])?, expected);
+        assert_eq!(run("104,1125899906842624,99", &[])?, vec![1125899906842624]);
+        Ok(())
+    }
+
+    #[test]
+    fn pauses_for_input() -> Result<()> {
+        let mut machine: Machine = "3,9,8,9,10,9,4,9,99,-1,8".parse()?;
+        assert_eq!(machine.run()?, State::NeedsInput);
+        assert_eq!(machine.pop_output(), None);
+        machine.push_input(8);
+        assert_eq!(machine.run()?, State::Halted);
+        assert_eq!(machine.pop_output(), Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_programs() {
+        assert!("1,2,x".parse::<Machine>().is_err());
+        assert!(run("42", &[]).is_err());
+        assert!(run("1,-1,0,0,99", &[]).is_err());
+        assert!(run("30001,0,0,0,99", &[]).is_err());
+        assert!(run("1101,9223372036854775807,1,0,99", &[]).is_err());
+        assert!(run("1102,9223372036854775807,2,0,99", &[]).is_err());
+        assert!(run("109,9223372036854775807,109,1,99", &[]).is_err());
+    }
+
+    #[test]
+    fn high_addresses_dont_need_huge_memory() -> Result<()> {
+        let mut machine: Machine = "1101,1,1,100000000000000,99".parse()?;
+        assert_eq!(machine.run()?, State::Halted);
+        assert_eq!(machine.read(100000000000000), 2);
+        assert_eq!(machine.read(100000000000001), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn endless_programs_can_be_cancelled() -> Result<()> {
+        let mut machine: Machine = "1105,1,0".parse()?;
+        let cancellation = Cancellation::default();
+        cancellation.cancel();
+        machine.set_cancellation(cancellation);
+        assert!(matches!(machine.run(), Err(CoreError::Interrupted(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_core::core;
+
+// Shared by every day that runs an Intcode program.
+pub mod intcode;
+
+// Generated by build_days.rs from the dNN.rs files in this directory.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -0,0 +1,78 @@
+use crate::core::{Answer, BlockSolver, CoreError, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    CalorieCounter::new(1).solver()
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    CalorieCounter::new(3).solver()
+}
+
+// Each block is the calories carried by one elf.
+#[derive(Debug)]
+struct CalorieCounter {
+    totals: Vec<u64>,
+    top: usize,
+}
+
+impl CalorieCounter {
+    fn new(top: usize) -> Self {
+        Self { totals: vec![], top }
+    }
+
+    fn most_carried(&mut self) -> u64 {
+        self.totals.sort_by(|a, b| b.cmp(a));
+        self.totals.iter().take(self.top).sum()
+    }
+}
+
+impl BlockSolver for CalorieCounter {
+    fn handle_block(&mut self, block: &[&str]) -> Result<()> {
+        let total = block
+            .iter()
+            .map(|line| line.parse::<u64>())
+            .sum::<std::result::Result<u64, _>>()?;
+        self.totals.push(total);
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        if self.totals.len() < self.top {
+            let msg = format!("Need at least {} elves", self.top);
+            return Err(CoreError::General(msg));
+        }
+        Ok(self.most_carried().into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Calorie Counting")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Calories carried by the best-stocked elves")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_total_each_elf() -> Result<()> {
+        let mut counter = CalorieCounter::new(2);
+        counter.handle_block(&["1000", "2000", "3000"])?;
+        counter.handle_block(&["4000"])?;
+        counter.handle_block(&["5000", "6000"])?;
+        assert_eq!(counter.totals, vec![6000, 4000, 11000]);
+        assert_eq!(counter.most_carried(), 17000);
+        assert!(counter.handle_block(&["lots"]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn needs_enough_elves() {
+        let mut counter = CalorieCounter::new(3);
+        counter.handle_block(&["1"]).unwrap();
+        assert!(counter.extract_solution().is_err());
+    }
+}
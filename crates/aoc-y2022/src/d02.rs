@@ -0,0 +1,110 @@
+use crate::core::{Answer, CoreError, Puzzle, PuzzlePart, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    RockPaperScissors::solver(PuzzlePart::One)
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    RockPaperScissors::solver(PuzzlePart::Two)
+}
+
+struct RockPaperScissors;
+
+impl Puzzle for RockPaperScissors {
+    type Parsed = Vec<Round>;
+
+    fn parse_line(rounds: &mut Vec<Round>, line: &str) -> Result<()> {
+        rounds.push(Round::from_string(line)?);
+        Ok(())
+    }
+
+    // The second column is the shape to play...
+    fn part_1(rounds: &Vec<Round>) -> Result<Answer> {
+        let total: u32 = rounds
+            .iter()
+            .map(|round| score(Shape(round.column), round.theirs))
+            .sum();
+        Ok(total.into())
+    }
+
+    // ...or, it turns out, whether to lose, draw or win.
+    fn part_2(rounds: &Vec<Round>) -> Result<Answer> {
+        let total: u32 = rounds
+            .iter()
+            .map(|round| score(round.theirs.offset(round.column + 2), round.theirs))
+            .sum();
+        Ok(total.into())
+    }
+
+    fn title() -> Option<&'static str> {
+        Some("Rock Paper Scissors")
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("Total score from following the strategy guide")
+    }
+}
+
+// 0 is rock, 1 paper and 2 scissors; each shape beats the one before it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+struct Shape(u8);
+
+impl Shape {
+    fn offset(&self, by: u8) -> Self {
+        Self((self.0 + by) % 3)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Round {
+    theirs: Shape,
+    column: u8,
+}
+
+impl Round {
+    fn from_string(line: &str) -> Result<Self> {
+        match line.as_bytes() {
+            [theirs @ b'A'..=b'C', b' ', column @ b'X'..=b'Z'] => Ok(Self {
+                theirs: Shape(theirs - b'A'),
+                column: column - b'X',
+            }),
+            _ => Err(CoreError::general("Expected a round like \"A Y\"")),
+        }
+    }
+}
+
+fn score(mine: Shape, theirs: Shape) -> u32 {
+    let outcome = match (mine.0 + 3 - theirs.0) % 3 {
+        0 => 3,
+        1 => 6,
+        _ => 0,
+    };
+    mine.0 as u32 + 1 + outcome
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_parse_rounds() {
+        let round = Round::from_string("C X").unwrap();
+        assert_eq!(
+            round,
+            Round {
+                theirs: Shape(2),
+                column: 0
+            }
+        );
+        assert!(Round::from_string("D X").is_err());
+        assert!(Round::from_string("A X ").is_err());
+    }
+
+    #[test]
+    fn can_score_rounds() {
+        let (rock, paper, scissors) = (Shape(0), Shape(1), Shape(2));
+        assert_eq!(score(paper, rock), 8);
+        assert_eq!(score(rock, paper), 1);
+        assert_eq!(score(scissors, scissors), 6);
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{
+    bitset::BitSet,
+    core::{Answer, CoreError, Puzzle, PuzzlePart, Result, Solver},
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Rucksacks::solver(PuzzlePart::One)
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Rucksacks::solver(PuzzlePart::Two)
+}
+
+struct Rucksacks;
+
+impl Puzzle for Rucksacks {
+    type Parsed = Vec<Rucksack>;
+
+    fn parse_line(rucksacks: &mut Vec<Rucksack>, line: &str) -> Result<()> {
+        rucksacks.push(Rucksack::from_string(line)?);
+        Ok(())
+    }
+
+    fn part_1(rucksacks: &Vec<Rucksack>) -> Result<Answer> {
+        let total = rucksacks
+            .iter()
+            .map(|rucksack| only_item(rucksack.left.intersection(rucksack.right)))
+            .sum::<Result<usize>>()?;
+        Ok(total.into())
+    }
+
+    fn part_2(rucksacks: &Vec<Rucksack>) -> Result<Answer> {
+        if !rucksacks.len().is_multiple_of(3) {
+            return Err(CoreError::general("Elves should be in groups of three"));
+        }
+        let total = rucksacks
+            .chunks(3)
+            .map(|group| {
+                let badge = group
+                    .iter()
+                    .map(Rucksack::items)
+                    .fold(BitSet::full(BitSet::CAPACITY), |a, b| a.intersection(b));
+                only_item(badge)
+            })
+            .sum::<Result<usize>>()?;
+        Ok(total.into())
+    }
+
+    fn title() -> Option<&'static str> {
+        Some("Rucksack Reorganization")
+    }
+
+    fn description() -> Option<&'static str> {
+        Some("Priorities of the items in both compartments, and of each group's badge")
+    }
+}
+
+// Items are stored by their priority, 1-52.
+#[derive(Debug)]
+struct Rucksack {
+    left: BitSet,
+    right: BitSet,
+}
+
+impl Rucksack {
+    fn from_string(line: &str) -> Result<Self> {
+        if !line.len().is_multiple_of(2) {
+            return Err(CoreError::general("Compartments should be the same size"));
+        }
+        let (left, right) = line.split_at(line.len() / 2);
+        Ok(Self {
+            left: compartment(left)?,
+            right: compartment(right)?,
+        })
+    }
+
+    fn items(&self) -> BitSet {
+        self.left.union(self.right)
+    }
+}
+
+fn compartment(items: &str) -> Result<BitSet> {
+    let mut compartment = BitSet::empty();
+    for c in items.chars() {
+        let priority = match c {
+            'a'..='z' => c as usize - 'a' as usize + 1,
+            'A'..='Z' => c as usize - 'A' as usize + 27,
+            _ => return Err(CoreError::General(format!("Unknown item {:?}", c))),
+        };
+        compartment.insert(priority);
+    }
+    Ok(compartment)
+}
+
+fn only_item(items: BitSet) -> Result<usize> {
+    match items.len() {
+        1 => Ok(items.iter().next().unwrap()),
+        n => Err(CoreError::General(format!("Expected one item, found {}", n))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_find_the_misplaced_item() -> Result<()> {
+        let rucksack = Rucksack::from_string("vJrwpWtwJgWrhcsFMMfFFhFp")?;
+        assert_eq!(only_item(rucksack.left.intersection(rucksack.right))?, 16);
+        let rucksack = Rucksack::from_string("PmmdzqPrVvPwwTWBwg")?;
+        assert_eq!(only_item(rucksack.left.intersection(rucksack.right))?, 42);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_odd_rucksacks() {
+        assert!(Rucksack::from_string("abc").is_err());
+        assert!(Rucksack::from_string("a1").is_err());
+        assert!(only_item(BitSet::empty()).is_err());
+    }
+}
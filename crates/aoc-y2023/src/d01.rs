@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use crate::core::{Answer, CoreError, Result, Solver};
+use crate::string_scanner::TokenIter;
+
+const ENGLISH_WORDS: [(&str, u32); 10] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+pub fn part_1() -> Box<dyn Solver> {
+    with_tokens(TokenTable::digits_only())
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    with_tokens(TokenTable::english())
+}
+
+pub fn with_tokens(tokens: TokenTable) -> Box<dyn Solver> {
+    Box::new(ExtractAndSum { tokens, total: 0 })
+}
+
+pub struct ExtractAndSum {
+    tokens: TokenTable,
+    total: u32,
+}
+
+impl Solver for ExtractAndSum {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        self.total += extract_number(line, &self.tokens)?;
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.total.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Trebuchet?!")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Calibration values from the first and last digit on each line")
+    }
+}
+
+// The digits 0-9 always count; the table adds any words that should count as
+// well. Tables can be parsed from text with one "word value" pair per line.
+#[derive(Debug, Clone)]
+pub struct TokenTable(Vec<(String, u32)>);
+
+impl TokenTable {
+    pub fn digits_only() -> Self {
+        Self::with_words(vec![])
+    }
+
+    pub fn english() -> Self {
+        let words = ENGLISH_WORDS
+            .iter()
+            .map(|(word, value)| (word.to_string(), *value))
+            .collect();
+        Self::with_words(words)
+    }
+
+    fn with_words(mut words: Vec<(String, u32)>) -> Self {
+        words.extend((0..=9).map(|digit: u32| (digit.to_string(), digit)));
+        Self(words)
+    }
+
+    fn tokens(&self) -> &[(String, u32)] {
+        &self.0
+    }
+}
+
+impl FromStr for TokenTable {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut words = vec![];
+        for line in s.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.split_whitespace().collect::<Vec<&str>>()[..] {
+                [word, value] => words.push((word.to_string(), value.parse()?)),
+                _ => {
+                    let msg = format!("Expected 'word value' but got '{}'", line);
+                    return Err(CoreError::general(&msg));
+                }
+            }
+        }
+        Ok(Self::with_words(words))
+    }
+}
+
+fn extract_number(line: &str, tokens: &TokenTable) -> Result<u32> {
+    let mut first_digit: Option<u32> = None;
+    let mut last_digit = 0;
+
+    for digit in TokenIter::new(line, tokens.tokens()) {
+        if first_digit.is_none() {
+            first_digit = Some(*digit);
+        }
+        last_digit = *digit;
+    }
+
+    let number = first_digit.map_or(0, |x| x * 10 + last_digit);
+
+    Ok(number)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn extracts_a_number_from_a_line() {
+        let tokens = TokenTable::digits_only();
+        assert_eq!(extract_number("1abc2", &tokens).unwrap(), 12);
+        assert_eq!(extract_number("pqr3stu8vwx", &tokens).unwrap(), 38);
+        assert_eq!(extract_number("a1b2c3d4e5f", &tokens).unwrap(), 15);
+        assert_eq!(extract_number("treb7uchet", &tokens).unwrap(), 77);
+    }
+
+    #[test]
+    fn digit_extractor() {
+        let tokens = TokenTable::english();
+        let all_digits: Vec<u32> = TokenIter::new("xtwone3four", tokens.tokens())
+            .copied()
+            .collect();
+        assert_eq!(all_digits, vec![2, 1, 3, 4]);
+    }
+
+    #[test]
+    fn can_use_a_custom_token_table() {
+        let tokens: TokenTable = "# french\nun 1\ndeux 2\ntrois 3\n".parse().unwrap();
+        assert_eq!(extract_number("xdeuxun7trois", &tokens).unwrap(), 23);
+        assert_eq!(extract_number("two1nine", &tokens).unwrap(), 11);
+
+        assert!("un".parse::<TokenTable>().is_err());
+        assert!("un one".parse::<TokenTable>().is_err());
+    }
+}
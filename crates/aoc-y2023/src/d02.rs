@@ -1,58 +1,54 @@
-use crate::core::{Result, Solver};
+use crate::core::{Answer, Puzzle, PuzzlePart, Result, Solver};
 use crate::string_scanner::StringScanner;
 
 pub fn part_1() -> Box<dyn Solver> {
-    let analyser = Part1 {
-        original: CubeSet::new(12, 13, 14),
-        sum: 0,
-    };
-    Box::new(analyser)
+    CubeConundrum::solver(PuzzlePart::One)
 }
 
 pub fn part_2() -> Box<dyn Solver> {
-    Box::<Part2>::default()
+    CubeConundrum::solver(PuzzlePart::Two)
 }
 
-#[derive(Debug)]
-pub struct Part1 {
-    original: CubeSet,
-    sum: u16,
-}
+const BAG: CubeSet = CubeSet::new(12, 13, 14);
 
-impl Solver for Part1 {
-    fn handle_line(&mut self, line: &str) -> Result<()> {
-        let mut scanner = StringScanner::new(line);
-        let game = Game::from_scanner(&mut scanner)?;
+struct CubeConundrum;
 
-        if game.is_possible(&self.original) {
-            self.sum += game.id;
-        }
+impl Puzzle for CubeConundrum {
+    type Parsed = Vec<Game>;
 
+    fn parse_line(games: &mut Vec<Game>, line: &str) -> Result<()> {
+        let mut scanner = StringScanner::new(line);
+        games.push(Game::from_scanner(&mut scanner)?);
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
-        Ok(self.sum.to_string())
+    fn part_1(games: &Vec<Game>) -> Result<Answer> {
+        let sum: u32 = games
+            .iter()
+            .filter(|game| game.is_possible(&BAG))
+            .map(|game| game.id as u32)
+            .sum();
+        Ok(sum.into())
     }
-}
-
-#[derive(Default)]
-pub struct Part2 {
-    sum: u32,
-}
 
-impl Solver for Part2 {
-    fn handle_line(&mut self, line: &str) -> Result<()> {
-        let mut scanner = StringScanner::new(line);
-        let game = Game::from_scanner(&mut scanner)?;
+    fn part_2(games: &Vec<Game>) -> Result<Answer> {
+        let sum: u32 = games
+            .iter()
+            .map(|game| game.minimal_cube_set().power() as u32)
+            .sum();
+        Ok(sum.into())
+    }
 
-        self.sum += game.minimal_cube_set().power() as u32;
+    fn title() -> Option<&'static str> {
+        Some("Cube Conundrum")
+    }
 
-        Ok(())
+    fn description() -> Option<&'static str> {
+        Some("Which games are possible with a bag of coloured cubes")
     }
 
-    fn extract_solution(&self) -> Result<String> {
-        Ok(self.sum.to_string())
+    fn dump(games: &Vec<Game>) -> Result<Option<String>> {
+        Ok(Some(format!("{:#?}", games)))
     }
 }
 
@@ -116,7 +112,7 @@ struct CubeSet {
 }
 
 impl CubeSet {
-    fn new(num_red: u16, num_green: u16, num_blue: u16) -> Self {
+    const fn new(num_red: u16, num_green: u16, num_blue: u16) -> Self {
         Self {
             num_red,
             num_green,
@@ -202,21 +198,18 @@ mod test {
 
     #[test]
     fn game_ids_are_summed_up() {
-        let mut g = Part1 {
-            original: CubeSet::new(12, 13, 14),
-            sum: 0,
-        };
-        g.handle_line("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")
-            .unwrap();
-        g.handle_line("Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue")
-            .unwrap();
-        g.handle_line("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
-            .unwrap();
-        g.handle_line("Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red")
-            .unwrap();
-        g.handle_line("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green")
-            .unwrap();
-        assert_eq!(g.sum, 8);
+        let mut games = vec![];
+        for line in [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+        ] {
+            CubeConundrum::parse_line(&mut games, line).unwrap();
+        }
+        assert_eq!(CubeConundrum::part_1(&games).unwrap(), "8");
+        assert_eq!(CubeConundrum::part_2(&games).unwrap(), "2286");
     }
 
     #[test]
@@ -17,7 +17,8 @@
  */
 
 use crate::{
-    core::{Result, Solver},
+    core::{Answer, Result, Solver},
+    grid::{self, Grid},
     string_scanner::StringScanner,
 };
 use std::collections::HashMap;
@@ -30,6 +31,9 @@ pub fn part_2() -> Box<dyn Solver> {
     Box::<SumOfGearRatios>::default()
 }
 
+const TITLE: &str = "Gear Ratios";
+const DESCRIPTION: &str = "Part numbers next to symbols in an engine schematic";
+
 #[derive(Default)]
 pub struct SumOfPartNumbers {
     lines: Vec<String>,
@@ -41,10 +45,18 @@ impl Solver for SumOfPartNumbers {
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
         let schematic = build_schematic(&self.lines)?;
         let sum: u32 = schematic.get_part_numbers().iter().map(|n| *n as u32).sum();
-        Ok(sum.to_string())
+        Ok(sum.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some(TITLE)
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(DESCRIPTION)
     }
 }
 
@@ -59,10 +71,18 @@ impl Solver for SumOfGearRatios {
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
         let schematic = build_schematic(&self.lines)?;
         let sum: u32 = schematic.get_gears().iter().map(|g| g.ratio()).sum();
-        Ok(sum.to_string())
+        Ok(sum.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some(TITLE)
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(DESCRIPTION)
     }
 }
 
@@ -113,7 +133,7 @@ impl Gear {
 
 #[derive(Debug)]
 struct Schematic {
-    squares: Vec<Vec<char>>,
+    squares: Grid<char>,
     numbers: Vec<Number>,
 }
 
@@ -125,19 +145,16 @@ impl Schematic {
     }
 
     fn width(&self) -> u8 {
-        self.squares[0].len() as u8
+        self.squares.width() as u8
     }
     fn height(&self) -> u8 {
-        self.squares.len() as u8
+        self.squares.height() as u8
     }
 
     fn from_lines(lines: &[&str]) -> Result<Self> {
-        let mut squares = vec![];
+        let squares = Grid::from_lines(lines)?;
         let mut numbers = vec![];
         for (y, line) in lines.iter().enumerate() {
-            let chars = line.chars().collect::<Vec<char>>();
-            squares.push(chars);
-
             let mut scanner = StringScanner::new(line);
             let mut x = 0;
 
@@ -167,13 +184,13 @@ impl Schematic {
 
     fn is_symbol_at(&self, point: Point) -> bool {
         let (x, y) = point;
-        let c = self.squares[y as usize][x as usize];
+        let c = self.squares[grid::Point::new(x as usize, y as usize)];
         !(c == '.' || c.is_ascii_digit())
     }
 
     fn is_star_at(&self, point: Point) -> bool {
         let (x, y) = point;
-        let c = self.squares[y as usize][x as usize];
+        let c = self.squares[grid::Point::new(x as usize, y as usize)];
         c == '*'
     }
 
@@ -236,9 +253,7 @@ impl Schematic {
                     continue;
                 }
 
-                potential_gears
-                    .entry(position)
-                    .or_insert_with(std::vec::Vec::new);
+                potential_gears.entry(position).or_default();
 
                 potential_gears
                     .get_mut(&position)
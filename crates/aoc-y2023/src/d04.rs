@@ -1,39 +1,41 @@
 use std::collections::VecDeque;
 
 use crate::{
-    core::{Result, Solver},
+    core::{Answer, Puzzle, PuzzlePart, Result, Solver},
     string_scanner::StringScanner,
 };
 
 pub fn part_1() -> Box<dyn Solver> {
-    Box::<Part1>::default()
+    Scratchcards::solver(PuzzlePart::One)
 }
 
 pub fn part_2() -> Box<dyn Solver> {
-    Box::<Part2>::default()
+    Scratchcards::solver(PuzzlePart::Two)
 }
 
-#[derive(Default)]
-pub struct Part1(CardCollection);
+struct Scratchcards;
+
+impl Puzzle for Scratchcards {
+    type Parsed = CardCollection;
 
-impl Solver for Part1 {
-    fn handle_line(&mut self, line: &str) -> Result<()> {
-        self.0.add_card_from_string(line)
+    fn parse_line(cards: &mut CardCollection, line: &str) -> Result<()> {
+        cards.add_card_from_string(line)
     }
-    fn extract_solution(&self) -> Result<String> {
-        Ok(self.0.total_points().to_string())
+
+    fn part_1(cards: &CardCollection) -> Result<Answer> {
+        Ok(cards.total_points().into())
     }
-}
 
-#[derive(Default)]
-pub struct Part2(CardCollection);
+    fn part_2(cards: &CardCollection) -> Result<Answer> {
+        Ok(cards.expanded_number().into())
+    }
 
-impl Solver for Part2 {
-    fn handle_line(&mut self, line: &str) -> Result<()> {
-        self.0.add_card_from_string(line)
+    fn title() -> Option<&'static str> {
+        Some("Scratchcards")
     }
-    fn extract_solution(&self) -> Result<String> {
-        Ok(self.0.expanded_number().to_string())
+
+    fn description() -> Option<&'static str> {
+        Some("Scratchcards whose matches win copies of later cards")
     }
 }
 
@@ -0,0 +1,550 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checkpoint::Checkpoints,
+    core::{Answer, BlockSolver, Cancellation, CoreError, Cost, Progress, Result, Solver},
+    string_scanner::StringScanner,
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    AlmanacSolver::new(SeedBehaviour::Simple).solver()
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    AlmanacSolver::new(SeedBehaviour::Range).solver()
+}
+
+// Expands every seed range and maps each seed individually.
+pub fn part_2_brute() -> Box<dyn Solver> {
+    let mut solver = AlmanacSolver::new(SeedBehaviour::Range);
+    solver.brute_force = true;
+    solver.solver()
+}
+
+// How many seeds the brute force goes through between progress reports.
+const PROGRESS_STEP: u64 = 1 << 20;
+
+pub struct AlmanacSolver {
+    almanac: Almanac,
+    brute_force: bool,
+    progress: Progress,
+    cancellation: Cancellation,
+    checkpoints: Checkpoints,
+}
+
+// How far the brute force has got: every seed before `next` has been mapped.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BruteForceState {
+    next: u64,
+    lowest: Option<u64>,
+}
+
+impl AlmanacSolver {
+    fn new(seed_behaviour: SeedBehaviour) -> Self {
+        Self {
+            almanac: Almanac::new(seed_behaviour),
+            brute_force: false,
+            progress: Progress::default(),
+            cancellation: Cancellation::default(),
+            checkpoints: Checkpoints::default(),
+        }
+    }
+}
+
+impl BlockSolver for AlmanacSolver {
+    fn handle_block(&mut self, block: &[&str]) -> Result<()> {
+        self.almanac.read_section(block)
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        let lowest = if self.brute_force {
+            let total = self.almanac.num_seeds();
+            let state: BruteForceState = self.checkpoints.load().unwrap_or_default();
+            let mut lowest = state.lowest;
+            let seeds = (state.next..).zip(self.almanac.seeds_from(state.next));
+            for (next, seed) in seeds {
+                if next.is_multiple_of(PROGRESS_STEP) {
+                    if self.cancellation.is_cancelled() {
+                        self.checkpoints.save_now(&BruteForceState { next, lowest })?;
+                        let progress = format!("{} of {} seeds", next, total);
+                        return Err(CoreError::Interrupted(progress));
+                    }
+                    self.progress.report(next, total);
+                    self.checkpoints.save(&BruteForceState { next, lowest })?;
+                }
+                let location = self.almanac.calculate_location(seed);
+                lowest = Some(lowest.map_or(location, |l: u64| l.min(location)));
+            }
+            self.progress.report(total, total);
+            lowest
+        } else {
+            self.almanac.lowest_location()
+        };
+        let solution = lowest.map_or(Answer::from("No value"), Answer::from);
+        Ok(solution)
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("If You Give A Seed A Fertilizer")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Seeds mapped through a chain of almanac ranges")
+    }
+
+    fn cost(&self) -> Cost {
+        match self.brute_force {
+            true => Cost::Slow,
+            false => Cost::Fast,
+        }
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        Ok(Some(format!("{:#?}", self.almanac)))
+    }
+
+    fn set_progress(&mut self, progress: Progress) {
+        self.progress = progress;
+    }
+
+    fn set_cancellation(&mut self, cancellation: Cancellation) {
+        self.cancellation = cancellation;
+    }
+
+    fn set_checkpoints(&mut self, checkpoints: Checkpoints) {
+        self.checkpoints = checkpoints;
+    }
+}
+
+#[derive(Debug)]
+enum SeedBehaviour {
+    Simple,
+    Range,
+}
+
+#[derive(Debug)]
+struct Almanac {
+    seed_behaviour: SeedBehaviour,
+    seeds: Vec<u64>,
+    value_maps: Vec<ValueMap>,
+}
+
+impl Almanac {
+    fn new(seed_behaviour: SeedBehaviour) -> Self {
+        Self {
+            seed_behaviour,
+            seeds: vec![],
+            value_maps: vec![],
+        }
+    }
+
+    // A section is either the seeds line, or a map's heading followed by its
+    // ranges.
+    fn read_section(&mut self, section: &[&str]) -> Result<()> {
+        let (heading, lines) = section.split_first().unwrap_or((&"", &[]));
+        if heading.starts_with("seeds: ") {
+            return self.read_seeds(heading);
+        }
+        if !heading.ends_with("map:") {
+            return Err(CoreError::General(format!("Expected a map, got {:?}", heading)));
+        }
+        self.value_maps.push(ValueMap::default());
+        lines.iter().try_for_each(|line| self.read_range(line))
+    }
+
+    fn read_seeds(&mut self, line: &str) -> Result<()> {
+        let mut scanner = StringScanner::new(line);
+        scanner.expect_string("seeds:")?;
+        let mut seeds = vec![];
+        while !scanner.is_finished() {
+            scanner.read_whitespace();
+            seeds.push(scanner.expect_uint::<u64>()?);
+        }
+        self.seeds = seeds;
+        Ok(())
+    }
+
+    fn read_range(&mut self, line: &str) -> Result<()> {
+        let mut scanner = StringScanner::new(line);
+        let destination_start: u64 = scanner.expect_uint()?;
+        scanner.read_whitespace();
+        let source_start: u64 = scanner.expect_uint()?;
+        scanner.read_whitespace();
+        let source_length: u64 = scanner.expect_uint()?;
+        let range = ValueMapRange {
+            destination_start,
+            source_start,
+            source_length,
+        };
+        let map = self
+            .value_maps
+            .last_mut()
+            .ok_or_else(|| CoreError::general("Range given before any map"))?;
+        map.0.push(range);
+        Ok(())
+    }
+
+    fn calculate_location(&self, value: u64) -> u64 {
+        self.value_maps
+            .iter()
+            .fold(value, |acc, map| map.map_value(acc))
+    }
+
+    fn num_seeds(&self) -> u64 {
+        match self.seed_behaviour {
+            SeedBehaviour::Simple => self.seeds.len() as u64,
+            SeedBehaviour::Range => self.seeds.chunks_exact(2).map(|pair| pair[1]).sum(),
+        }
+    }
+
+    // Every seed, in order, after skipping the first `skip`. Skipped ranges
+    // are jumped over rather than walked through.
+    fn seeds_from(&self, skip: u64) -> Box<dyn Iterator<Item = u64> + '_> {
+        match self.seed_behaviour {
+            SeedBehaviour::Simple => Box::new(self.seeds.iter().copied().skip(skip as usize)),
+            SeedBehaviour::Range => {
+                let mut skip = skip;
+                Box::new(self.seeds.chunks_exact(2).flat_map(move |pair| {
+                    let (start, length) = (pair[0], pair[1]);
+                    let skipped = skip.min(length);
+                    skip -= skipped;
+                    start + skipped..start + length
+                }))
+            }
+        }
+    }
+
+    fn location_numbers(&self) -> impl Iterator<Item = u64> + '_ {
+        self.seeds_from(0).map(|n| self.calculate_location(n))
+    }
+
+    fn lowest_location(&self) -> Option<u64> {
+        match self.seed_behaviour {
+            SeedBehaviour::Simple => self.location_numbers().min(),
+            SeedBehaviour::Range => self.lowest_location_by_ranges(),
+        }
+    }
+
+    // Rather than expanding every seed, push whole (start, end) ranges through
+    // the maps, splitting them wherever they straddle a map range boundary.
+    fn lowest_location_by_ranges(&self) -> Option<u64> {
+        let seed_ranges: Vec<(u64, u64)> = self
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[0] + pair[1]))
+            .collect();
+
+        self.value_maps
+            .iter()
+            .fold(seed_ranges, |ranges, map| map.map_ranges(ranges))
+            .iter()
+            .map(|(start, _)| *start)
+            .min()
+    }
+}
+
+#[derive(Debug, Default)]
+struct ValueMap(Vec<ValueMapRange>);
+
+impl ValueMap {
+    fn map_value(&self, value: u64) -> u64 {
+        self.0
+            .iter()
+            .find_map(|range| range.map_value(value))
+            .unwrap_or(value)
+    }
+
+    fn map_ranges(&self, ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+        let mut unmapped = ranges;
+        let mut mapped = vec![];
+
+        for map_range in self.0.iter() {
+            let source_end = map_range.source_start + map_range.source_length;
+            let mut remaining = vec![];
+
+            for (start, end) in unmapped {
+                let overlap_start = start.max(map_range.source_start);
+                let overlap_end = end.min(source_end);
+                if overlap_start >= overlap_end {
+                    remaining.push((start, end));
+                    continue;
+                }
+
+                let offset = overlap_start - map_range.source_start;
+                let destination = map_range.destination_start + offset;
+                mapped.push((destination, destination + (overlap_end - overlap_start)));
+
+                if start < overlap_start {
+                    remaining.push((start, overlap_start));
+                }
+                if overlap_end < end {
+                    remaining.push((overlap_end, end));
+                }
+            }
+
+            unmapped = remaining;
+        }
+
+        mapped.extend(unmapped);
+        mapped
+    }
+}
+
+#[derive(Debug)]
+struct ValueMapRange {
+    destination_start: u64,
+    source_start: u64,
+    source_length: u64,
+}
+
+impl ValueMapRange {
+    fn map_value(&self, value: u64) -> Option<u64> {
+        let end = self.source_start + self.source_length;
+        if (self.source_start..end).contains(&value) {
+            let delta = value - self.source_start;
+            Some(self.destination_start + delta)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+    use crate::gen;
+
+    #[test]
+    fn map_range_converts_numbers_correctly() {
+        let mr = ValueMapRange {
+            destination_start: 50,
+            source_start: 98,
+            source_length: 2,
+        };
+        assert_eq!(mr.map_value(97), None);
+        assert_eq!(mr.map_value(98), Some(50));
+        assert_eq!(mr.map_value(99), Some(51));
+        assert_eq!(mr.map_value(100), None);
+    }
+
+    #[test]
+    fn value_is_mapped_correctly() {
+        let value_map = ValueMap(vec![
+            ValueMapRange {
+                destination_start: 50,
+                source_start: 98,
+                source_length: 2,
+            },
+            ValueMapRange {
+                destination_start: 52,
+                source_start: 50,
+                source_length: 48,
+            },
+        ]);
+
+        assert_eq!(value_map.map_value(49), 49);
+        assert_eq!(value_map.map_value(50), 52);
+        assert_eq!(value_map.map_value(51), 53);
+        assert_eq!(value_map.map_value(97), 99);
+        assert_eq!(value_map.map_value(98), 50);
+        assert_eq!(value_map.map_value(99), 51);
+        assert_eq!(value_map.map_value(100), 100);
+    }
+
+    fn read_sections(almanac: &mut Almanac, lines: &[&str]) {
+        for section in lines.split(|line| line.is_empty()) {
+            almanac.read_section(section).unwrap();
+        }
+    }
+
+    fn sample_almanac() -> Almanac {
+        sample_almanac_with(SeedBehaviour::Simple)
+    }
+
+    fn sample_almanac_with(seed_behaviour: SeedBehaviour) -> Almanac {
+        let mut almanac = Almanac::new(seed_behaviour);
+        let lines = [
+            "seeds: 79 14 55 13",
+            "",
+            "seed-to-soil map:",
+            "50 98 2",
+            "52 50 48",
+            "",
+            "soil-to-fertilizer map:",
+            "0 15 37",
+            "37 52 2",
+            "39 0 15",
+            "",
+            "fertilizer-to-water map:",
+            "49 53 8",
+            "0 11 42",
+            "42 0 7",
+            "57 7 4",
+            "",
+            "water-to-light map:",
+            "88 18 7",
+            "18 25 70",
+            "",
+            "light-to-temperature map:",
+            "45 77 23",
+            "81 45 19",
+            "68 64 13",
+            "",
+            "temperature-to-humidity map:",
+            "0 69 1",
+            "1 0 69",
+            "",
+            "humidity-to-location map:",
+            "60 56 37",
+            "56 93 4",
+        ];
+        read_sections(&mut almanac, &lines);
+        almanac
+    }
+
+    #[test]
+    fn can_calculate_location() {
+        let almanac = sample_almanac();
+        assert_eq!(almanac.calculate_location(79), 82);
+    }
+
+    #[test]
+    fn location_numbers() {
+        let almanac = sample_almanac();
+        let locations = almanac.location_numbers().collect::<Vec<u64>>();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn expanding_seeds() {
+        let almanac = sample_almanac_with(SeedBehaviour::Range);
+        let seeds: Vec<u64> = almanac.seeds_from(0).collect();
+        assert_eq!(
+            seeds,
+            vec![
+                79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 55, 56, 57, 58, 59, 60, 61,
+                62, 63, 64, 65, 66, 67,
+            ]
+        );
+        let rest: Vec<u64> = almanac.seeds_from(16).collect();
+        assert_eq!(rest, (57..68).collect::<Vec<u64>>());
+        assert_eq!(almanac.seeds_from(27).count(), 0);
+        assert_eq!(almanac.seeds_from(100).count(), 0);
+    }
+
+    #[test]
+    fn can_map_ranges() {
+        let value_map = ValueMap(vec![ValueMapRange {
+            destination_start: 50,
+            source_start: 98,
+            source_length: 2,
+        }]);
+        let mut ranges = value_map.map_ranges(vec![(90, 105)]);
+        ranges.sort();
+        assert_eq!(ranges, vec![(50, 52), (90, 98), (100, 105)]);
+    }
+
+    #[test]
+    fn lowest_location_with_ranges() {
+        let almanac = sample_almanac_with(SeedBehaviour::Range);
+        assert_eq!(almanac.lowest_location_by_ranges(), Some(46));
+        assert_eq!(almanac.location_numbers().min(), Some(46));
+    }
+
+    #[test]
+    fn ranges_agree_with_expansion_on_random_almanacs() {
+        let build = |lines: &[String]| {
+            let mut almanac = Almanac::new(SeedBehaviour::Range);
+            let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+            read_sections(&mut almanac, &lines);
+            almanac
+        };
+        gen::assert_implementations_agree(
+            5,
+            50,
+            |lines| build(lines).location_numbers().min(),
+            |lines| build(lines).lowest_location_by_ranges(),
+        );
+    }
+
+    #[test]
+    fn can_read_whole_input() {
+        let mut solver = part_1();
+        solver
+            .handle_input("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 48\n")
+            .unwrap();
+        assert_eq!(solver.extract_solution().unwrap(), "14");
+
+        let mut solver = part_1();
+        let err = solver.handle_input("seeds: 79\n\n50 98 2\n").unwrap_err();
+        assert!(matches!(err, CoreError::Parse { line: 3, .. }));
+    }
+
+    #[test]
+    fn can_dump_the_almanac() {
+        let mut solver = part_1();
+        solver.handle_input("seeds: 79 14\n").unwrap();
+        let dump = solver.dump().unwrap().unwrap();
+        assert!(dump.starts_with("Almanac {"));
+        assert!(dump.contains("79,"));
+    }
+
+    #[test]
+    fn brute_force_reports_progress() {
+        let reports = std::sync::Arc::new(std::sync::Mutex::new(vec![]));
+        let mut solver = part_2_brute();
+        let seen = reports.clone();
+        solver.set_progress(Progress::new(move |done, total| {
+            seen.lock().unwrap().push((done, total))
+        }));
+        solver.handle_input("seeds: 79 14 55 13\n").unwrap();
+        assert_eq!(solver.extract_solution().unwrap(), "55");
+        assert_eq!(*reports.lock().unwrap(), [(0, 27), (27, 27)]);
+    }
+
+    #[test]
+    fn brute_force_resumes_from_checkpoint() {
+        let path = std::env::temp_dir().join(format!("aoc-d05-{}.json", std::process::id()));
+        let input = "seeds: 79 14 55 13\n";
+        let checkpoints = || {
+            Checkpoints::with_interval(path.clone(), input.as_bytes(), true, Duration::ZERO)
+        };
+        let state = BruteForceState {
+            next: 27,
+            lowest: Some(7),
+        };
+        checkpoints().save(&state).unwrap();
+
+        let mut solver = part_2_brute();
+        solver.set_checkpoints(checkpoints());
+        solver.handle_input(input).unwrap();
+        assert_eq!(solver.extract_solution().unwrap(), "7");
+        checkpoints().clear().unwrap();
+    }
+
+    #[test]
+    fn brute_force_saves_when_interrupted() {
+        let path = std::env::temp_dir().join(format!("aoc-d05-int-{}.json", std::process::id()));
+        let input = "seeds: 79 14 55 13\n";
+        // Never saves on its own, so anything written is down to the interrupt.
+        let checkpoints = || {
+            Checkpoints::with_interval(path.clone(), input.as_bytes(), true, Duration::MAX)
+        };
+
+        let mut solver = part_2_brute();
+        solver.set_checkpoints(checkpoints());
+        let cancellation = Cancellation::default();
+        cancellation.cancel();
+        solver.set_cancellation(cancellation);
+        solver.handle_input(input).unwrap();
+        assert!(matches!(
+            solver.extract_solution(),
+            Err(CoreError::Interrupted(_))
+        ));
+
+        let state: BruteForceState = checkpoints().load().unwrap();
+        assert_eq!(state.next, 0);
+        checkpoints().clear().unwrap();
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
-    core::{CoreError, Result, Solver},
+    core::{Answer, CoreError, Result, Solver},
+    maths,
     string_scanner::StringScanner,
 };
 
@@ -20,9 +21,17 @@ impl Solver for NumWaysCalculator {
         self.0.add_line(line)
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
         let races = self.0.build()?;
-        Ok(races.margin_of_error().to_string())
+        Ok(races.margin_of_error()?.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Wait For It")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Ways to beat the record distance in each boat race")
     }
 }
 
@@ -100,7 +109,7 @@ impl RacesBuilder for ConcatRacesBuilder {
     }
 
     fn add_line(&mut self, line: &str) -> Result<()> {
-        let second_part = match line.split(':').last() {
+        let second_part = match line.split(':').next_back() {
             Some(x) => x,
             None => {
                 return Err(CoreError::general("No ':' found in input string"));
@@ -114,7 +123,7 @@ impl RacesBuilder for ConcatRacesBuilder {
 struct Races(Vec<Race>);
 
 impl Races {
-    fn margin_of_error(&self) -> u64 {
+    fn margin_of_error(&self) -> Result<u64> {
         self.0.iter().map(|race| race.num_ways_to_win()).product()
     }
 }
@@ -132,28 +141,20 @@ impl Race {
         }
     }
 
-    fn num_ways_to_win(&self) -> u64 {
+    fn num_ways_to_win(&self) -> Result<u64> {
         num_ways_to_win(self.total_time, self.distance_to_beat)
     }
 }
 
-fn num_ways_to_win(total_time: u64, distance_to_beat: u64) -> u64 {
-    for hold_time in 0..total_time {
-        if calculate_distance(total_time, hold_time) > distance_to_beat {
-            let result = total_time - 2 * hold_time + 1;
-            return result;
-        }
-    }
-    0
-}
-
-fn calculate_distance(total_time: u64, hold_time: u64) -> u64 {
-    if hold_time <= total_time {
-        let speed = hold_time;
-        let remaining_time = total_time - hold_time;
-        speed * remaining_time
-    } else {
-        0
+// Holding for h gives h * (total_time - h), so we want the h where
+// -h² + total_time * h - distance_to_beat > 0.
+fn num_ways_to_win(total_time: u64, distance_to_beat: u64) -> Result<u64> {
+    let too_big = |n: u64| CoreError::General(format!("{} is too big for a race", n));
+    let t = i64::try_from(total_time).map_err(|_| too_big(total_time))?;
+    let d = i64::try_from(distance_to_beat).map_err(|_| too_big(distance_to_beat))?;
+    match maths::quadratic_integer_solutions(-1, t, -d) {
+        Some(hold_times) => Ok(hold_times.end().abs_diff(*hold_times.start()) + 1),
+        None => Ok(0),
     }
 }
 
@@ -161,6 +162,16 @@ fn calculate_distance(total_time: u64, hold_time: u64) -> u64 {
 mod test {
     use super::*;
 
+    fn calculate_distance(total_time: u64, hold_time: u64) -> u64 {
+        if hold_time <= total_time {
+            let speed = hold_time;
+            let remaining_time = total_time - hold_time;
+            speed * remaining_time
+        } else {
+            0
+        }
+    }
+
     #[test]
     fn can_calculate_distance() {
         assert_eq!(calculate_distance(7, 0), 0);
@@ -175,14 +186,27 @@ mod test {
 
     #[test]
     fn can_calculate_num_ways_to_win() {
-        assert_eq!(num_ways_to_win(7, 9), 4);
-        assert_eq!(num_ways_to_win(15, 40), 8);
-        assert_eq!(num_ways_to_win(30, 200), 9);
+        assert_eq!(num_ways_to_win(7, 9).unwrap(), 4);
+        assert_eq!(num_ways_to_win(15, 40).unwrap(), 8);
+        assert_eq!(num_ways_to_win(30, 200).unwrap(), 9);
+        assert!(num_ways_to_win(u64::MAX, 9).is_err());
+    }
+
+    #[test]
+    fn closed_form_agrees_with_trying_every_hold_time() {
+        for total_time in 0..30 {
+            for distance_to_beat in 0..250 {
+                let expected = (0..=total_time)
+                    .filter(|h| calculate_distance(total_time, *h) > distance_to_beat)
+                    .count() as u64;
+                assert_eq!(num_ways_to_win(total_time, distance_to_beat).unwrap(), expected);
+            }
+        }
     }
 
     #[test]
     fn can_calculate_margin_of_error() {
         let races = Races(vec![Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]);
-        assert_eq!(races.margin_of_error(), 288);
+        assert_eq!(races.margin_of_error().unwrap(), 288);
     }
 }
@@ -1,7 +1,8 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::cmp::Ordering;
 
 use crate::{
-    core::{CoreError, Result, Solver},
+    core::{Answer, CoreError, Cost, Result, Solver},
+    counter::Counter,
     string_scanner::StringScanner,
 };
 
@@ -13,6 +14,11 @@ pub fn part_2() -> Box<dyn Solver> {
     Box::new(HandsWithBids::new(CompareType::Joker))
 }
 
+// Tries every possible joker substitution rather than counting labels.
+pub fn part_2_enumerate() -> Box<dyn Solver> {
+    Box::new(HandsWithBids::new(CompareType::JokerEnumerated))
+}
+
 #[derive(Debug)]
 struct HandsWithBids(Vec<HandWithBid>, CompareType);
 
@@ -22,8 +28,23 @@ impl Solver for HandsWithBids {
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
-        Ok(self.total_score().to_string())
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.total_score().into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Camel Cards")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Ranking poker-like hands by type and then card by card")
+    }
+
+    fn cost(&self) -> Cost {
+        match self.1 {
+            CompareType::JokerEnumerated => Cost::Slow,
+            _ => Cost::Fast,
+        }
     }
 }
 
@@ -32,11 +53,12 @@ impl HandsWithBids {
         Self(vec![], compare_type)
     }
 
-    fn total_score(&self) -> u64 {
-        let mut other = self.0.clone();
-        other.sort_by(|a, b| self.1.compare_hands(&a.hand, &b.hand));
+    fn total_score(&mut self) -> u64 {
+        let compare_type = &self.1;
+        self.0
+            .sort_by(|a, b| compare_type.compare_hands(&a.hand, &b.hand));
 
-        other
+        self.0
             .iter()
             .enumerate()
             .map(|(i, hand_with_bid)| {
@@ -51,13 +73,17 @@ impl HandsWithBids {
 enum CompareType {
     Basic,
     Joker,
+    JokerEnumerated,
 }
 
 impl CompareType {
     fn compare_hands(&self, hand_1: &Hand, hand_2: &Hand) -> Ordering {
         let hand_type_compare = match self {
             Self::Basic => hand_1.hand_type().cmp(&hand_2.hand_type()),
-            Self::Joker => hand_1.best_hand_type().cmp(&hand_2.best_hand_type()),
+            Self::Joker => hand_1
+                .best_hand_type_by_counts()
+                .cmp(&hand_2.best_hand_type_by_counts()),
+            Self::JokerEnumerated => hand_1.best_hand_type().cmp(&hand_2.best_hand_type()),
         };
 
         match hand_type_compare {
@@ -65,7 +91,7 @@ impl CompareType {
                 for (c_1, c_2) in hand_1.0.iter().zip(hand_2.0.iter()) {
                     let label_compare = match self {
                         Self::Basic => c_1.cmp(c_2),
-                        Self::Joker => c_1.joker_cmp(c_2),
+                        Self::Joker | Self::JokerEnumerated => c_1.joker_cmp(c_2),
                     };
                     match label_compare {
                         Ordering::Equal => {}
@@ -118,39 +144,25 @@ impl Hand {
     }
 
     fn hand_type(&self) -> HandType {
-        let mut label_counts = HashMap::new();
-        for label in self.0.iter() {
-            label_counts
-                .entry(label)
-                .and_modify(|count| *count += 1)
-                .or_insert(1_u8);
-        }
+        let label_counts: Counter<&Label> = self.0.iter().collect();
+        hand_type_from_counts(&label_counts.counts_sorted())
+    }
 
-        let mut counts = label_counts.values().copied().collect::<Vec<u8>>();
-        counts.sort();
-
-        match counts.last() {
-            Some(5) => HandType::FiveOfAKind,
-            Some(4) => HandType::FourOfAKind,
-            Some(3) => {
-                if counts[0] == 2 {
-                    HandType::FullHouse
-                } else {
-                    HandType::ThreeOfAKind
-                }
-            }
-            Some(2) => {
-                if counts.len() == 3 {
-                    // Must be 1, 2, 2
-                    HandType::TwoPair
-                } else {
-                    HandType::OnePair
-                }
-            }
-            _ => HandType::HighCard,
+    // Jokers always do best by joining whichever label is already most common.
+    fn best_hand_type_by_counts(&self) -> HandType {
+        let label_counts: Counter<&Label> = self.0.iter().filter(|l| !l.is_joker()).collect();
+        let num_jokers = self.0.iter().filter(|l| l.is_joker()).count();
+
+        let mut counts = label_counts.counts_sorted();
+        match counts.last_mut() {
+            Some(highest) => *highest += num_jokers,
+            None => counts.push(num_jokers),
         }
+        hand_type_from_counts(&counts)
     }
 
+    // The brute-force versions below try every possible joker substitution;
+    // they're also a reference for checking best_hand_type_by_counts.
     fn possible_hands(&self) -> Vec<Self> {
         let mut concrete = vec![];
         let mut might_be_expanded = vec![self.clone()];
@@ -186,6 +198,29 @@ impl Hand {
     }
 }
 
+fn hand_type_from_counts(counts: &[usize]) -> HandType {
+    match counts.last() {
+        Some(5) => HandType::FiveOfAKind,
+        Some(4) => HandType::FourOfAKind,
+        Some(3) => {
+            if counts[0] == 2 {
+                HandType::FullHouse
+            } else {
+                HandType::ThreeOfAKind
+            }
+        }
+        Some(2) => {
+            if counts.len() == 3 {
+                // Must be 1, 2, 2
+                HandType::TwoPair
+            } else {
+                HandType::OnePair
+            }
+        }
+        _ => HandType::HighCard,
+    }
+}
+
 #[derive(Default, Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 enum HandType {
     #[default]
@@ -269,6 +304,7 @@ impl Label {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::gen;
 
     fn make_hand(line: &str) -> Hand {
         let mut scanner = StringScanner::new(line);
@@ -333,7 +369,7 @@ mod test {
 
     #[test]
     fn total_score() {
-        let hands_with_bids = make_hands_with_bids(CompareType::Basic);
+        let mut hands_with_bids = make_hands_with_bids(CompareType::Basic);
         assert_eq!(hands_with_bids.total_score(), 6440);
     }
 
@@ -370,7 +406,47 @@ mod test {
 
     #[test]
     fn total_score_with_jokers() {
-        let hands_with_bids = make_hands_with_bids(CompareType::Joker);
+        let mut hands_with_bids = make_hands_with_bids(CompareType::Joker);
         assert_eq!(hands_with_bids.total_score(), 5905);
+        let mut hands_with_bids = make_hands_with_bids(CompareType::JokerEnumerated);
+        assert_eq!(hands_with_bids.total_score(), 5905);
+    }
+
+    #[test]
+    fn best_hand_type_by_counts() {
+        for hand_string in ["T55J5", "KTJJT", "QQQJA"] {
+            let hand = make_hand(hand_string);
+            assert_eq!(hand.best_hand_type_by_counts(), HandType::FourOfAKind);
+        }
+        assert_eq!(
+            make_hand("JJJJJ").best_hand_type_by_counts(),
+            HandType::FiveOfAKind
+        );
+    }
+
+    #[test]
+    fn counting_agrees_with_enumeration_on_random_hands() {
+        let hands = |lines: &[String]| -> Vec<Hand> {
+            lines
+                .iter()
+                .map(|line| HandWithBid::from_string(line).unwrap().hand)
+                .collect()
+        };
+        gen::assert_implementations_agree(
+            7,
+            200,
+            |lines| {
+                hands(lines)
+                    .iter()
+                    .map(Hand::best_hand_type)
+                    .collect::<Vec<_>>()
+            },
+            |lines| {
+                hands(lines)
+                    .iter()
+                    .map(Hand::best_hand_type_by_counts)
+                    .collect::<Vec<_>>()
+            },
+        );
     }
 }
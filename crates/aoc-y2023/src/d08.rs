@@ -1,5 +1,5 @@
 use crate::{
-    core::{CoreError, Result, Solver},
+    core::{Answer, CoreError, Result, Solver},
     maths,
     string_scanner::StringScanner,
 };
@@ -13,6 +13,9 @@ pub fn part_2() -> Box<dyn Solver> {
     Box::<Part2>::default()
 }
 
+const TITLE: &str = "Haunted Wasteland";
+const DESCRIPTION: &str = "Following left/right instructions through a network of nodes";
+
 #[derive(Debug, Default)]
 struct Part1(MapBuilder);
 
@@ -22,10 +25,22 @@ impl Solver for Part1 {
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
         let map = self.0.build()?;
         let num_steps = map.calculate_distance(NodeId::new("AAA"), &is_zzz);
-        Ok(num_steps.to_string())
+        Ok(num_steps.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some(TITLE)
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(DESCRIPTION)
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        Ok(Some(format!("{:#?}", self.0.build()?)))
     }
 }
 
@@ -38,7 +53,7 @@ impl Solver for Part2 {
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
         let map = self.0.build()?;
         let start_nodes = map.start_nodes();
         let nums: Vec<u64> = start_nodes
@@ -46,7 +61,19 @@ impl Solver for Part2 {
             .map(|node_id| map.calculate_distance(node_id.clone(), &ends_with_z))
             .collect();
         let total = maths::lcm(&nums);
-        Ok(total.map_or("".to_string(), |n| n.to_string()))
+        Ok(total.map_or(Answer::from(""), Answer::from))
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some(TITLE)
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some(DESCRIPTION)
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        Ok(Some(format!("{:#?}", self.0.build()?)))
     }
 }
 
@@ -74,7 +101,9 @@ impl Map {
             let direction = directions.next().unwrap();
 
             let node = self.nodes.get(&current_node_id).unwrap();
-            current_node_id = node.next_node_id(*direction);
+            let next_node_id = node.next_node_id(*direction);
+            tracing::trace!(step = num_steps, from = ?current_node_id, to = ?next_node_id, ?direction);
+            current_node_id = next_node_id;
 
             num_steps += 1;
 
@@ -83,6 +112,7 @@ impl Map {
             }
         }
 
+        tracing::debug!(start = ?start_id, end = ?current_node_id, num_steps, "reached end");
         num_steps
     }
 
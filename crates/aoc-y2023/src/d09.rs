@@ -1,6 +1,6 @@
 use std::{collections::VecDeque, num::ParseIntError};
 
-use crate::core::{CoreError, Result as CoreResult, Solver};
+use crate::core::{Answer, CoreError, Result as CoreResult, Solver};
 
 pub fn part_1() -> Box<dyn Solver> {
     Box::new(Part1(0, Direction::Right))
@@ -33,8 +33,16 @@ impl Solver for Part1 {
         Ok(())
     }
 
-    fn extract_solution(&self) -> CoreResult<String> {
-        Ok(self.0.to_string())
+    fn extract_solution(&mut self) -> CoreResult<Answer> {
+        Ok(self.0.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Mirage Maintenance")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Extrapolating sequences from their differences")
     }
 }
 
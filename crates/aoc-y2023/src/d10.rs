@@ -1,6 +1,8 @@
+use std::fmt;
+
 use crate::{
-    core::{CoreError, Result, Solver},
-    grid::{Direction, Grid},
+    core::{Answer, CoreError, Result, Solver, Visualize},
+    grid::{interior_points, polygon_twice_area, Direction, Grid, IPoint},
 };
 
 pub fn part_1() -> Box<dyn Solver> {
@@ -26,20 +28,44 @@ impl Solver for Solution {
         Ok(())
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
         let maze = self.0.build()?.solve()?;
         let distance = match &self.1 {
             Part::One => maze.max_distance_from_start(),
             Part::Two => maze.num_contained_points(),
         };
-        Ok(distance.to_string())
+        Ok(distance.into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Pipe Maze")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("The loop of pipe that runs through the starting tile")
+    }
+
+    fn dump(&self) -> Result<Option<String>> {
+        Ok(Some(format!("{:#?}", self.0.build()?)))
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+// The maze as given, then just the loop.
+impl Visualize for Solution {
+    fn frames(&self) -> Result<Vec<String>> {
+        let maze = self.0.build()?;
+        let solved = maze.solve()?;
+        Ok(vec![maze.to_string(), solved.to_string()])
     }
 }
 
 #[derive(Debug)]
 struct SolvedMaze {
-    tiles: Vec<Tile>,
-    grid: Grid,
+    tiles: Grid<Tile>,
     path: Path,
 }
 
@@ -49,57 +75,44 @@ impl SolvedMaze {
     }
 
     fn num_contained_points(&self) -> usize {
-        let mut inside = false;
-        use Tile::{Ground, NorthEast, NorthWest, Vertical};
-        let num = self
-            .tiles
+        let corners: Vec<IPoint> = self
+            .path
+            .0
             .iter()
-            .filter(|tile| match tile {
-                Ground => inside,
-                Vertical | NorthEast | NorthWest => {
-                    inside = !inside;
-                    false
-                }
-                _ => false,
-            })
-            .count();
-
-        num
+            .map(|idx| self.tiles.to_point(*idx).into())
+            .collect();
+        let twice_area = polygon_twice_area(&corners);
+        interior_points(twice_area, self.path.len() as u64) as usize
     }
 }
 
-impl ToString for SolvedMaze {
-    fn to_string(&self) -> String {
-        tiles_to_string(&self.tiles, self.grid.width())
+impl fmt::Display for SolvedMaze {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tiles.render(|tile| tile.to_display_char()))
     }
 }
 
 #[derive(Debug)]
 struct Maze {
     start_index: usize,
-    tiles: Vec<Tile>,
-    grid: Grid,
+    tiles: Grid<Tile>,
 }
 
-impl ToString for Maze {
-    fn to_string(&self) -> String {
-        tiles_to_string(&self.tiles, self.grid.width())
+impl fmt::Display for Maze {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.tiles.render(|tile| tile.to_display_char()))
     }
 }
 
 impl Maze {
     fn solve(&self) -> Result<SolvedMaze> {
         let path = self.find_path()?;
-        let mut tiles: Vec<Tile> = self.grid.indices().map(|_| Tile::Ground).collect();
+        let mut tiles = self.tiles.map(|_| Tile::Ground);
         for idx in path.0.iter() {
             tiles[*idx] = self.tiles[*idx];
         }
 
-        Ok(SolvedMaze {
-            tiles,
-            grid: self.grid.clone(),
-            path,
-        })
+        Ok(SolvedMaze { tiles, path })
     }
 
     fn find_path(&self) -> Result<Path> {
@@ -131,7 +144,7 @@ impl Maze {
 
     fn starting_paths(&self) -> Result<(Path, Path)> {
         let mut paths: Vec<Path> = self
-            .grid
+            .tiles
             .neighbours(self.start_index)
             .iter()
             .filter_map(|neighbour_idx| {
@@ -155,20 +168,10 @@ impl Maze {
     }
 
     fn neighbours(&self, idx: usize) -> Vec<usize> {
-        use Direction::*;
-        let directions = match self.tiles.get(idx) {
-            Some(Tile::Vertical) => vec![North, South],
-            Some(Tile::Horizontal) => vec![East, West],
-            Some(Tile::NorthEast) => vec![North, East],
-            Some(Tile::NorthWest) => vec![North, West],
-            Some(Tile::SouthWest) => vec![South, West],
-            Some(Tile::SouthEast) => vec![South, East],
-            _ => vec![],
-        };
-
-        directions
+        self.tiles[idx]
+            .connections()
             .iter()
-            .flat_map(|direction| self.grid.neighbour(idx, *direction))
+            .flat_map(|direction| self.tiles.neighbour(idx, *direction))
             .collect()
     }
 }
@@ -183,40 +186,20 @@ impl MazeBuilder {
     }
 
     fn build(&self) -> Result<Maze> {
-        let height = self.0.len();
-        let width = self.0[0].len();
-        let grid = Grid::new(width, height);
-
-        let mut start_index = None;
-        let mut i = 0;
-        let mut tiles = vec![];
-
-        for line in self.0.iter() {
-            for c in line.chars() {
-                let tile = Tile::from_char(c)?;
-                tiles.push(tile);
-                if tile == Tile::Start {
-                    start_index = Some(i);
-                }
-                i += 1;
-            }
-        }
+        let mut tiles = Grid::<Tile>::from_lines(&self.0)?;
 
-        let start_index = match start_index {
+        let start_index = match tiles.find(|tile| *tile == Tile::Start) {
             Some(x) => x,
             None => {
                 return Err(CoreError::general("No start tile found"));
             }
         };
 
-        let start_tile = calculate_start_tile(&tiles, start_index, &grid)?;
+        let start_tile = calculate_start_tile(&tiles, start_index)?;
+        tracing::debug!(start_index, ?start_tile, "detected start tile");
         tiles[start_index] = start_tile;
 
-        Ok(Maze {
-            tiles,
-            start_index,
-            grid,
-        })
+        Ok(Maze { tiles, start_index })
     }
 }
 
@@ -232,8 +215,10 @@ enum Tile {
     Start,
 }
 
-impl Tile {
-    fn from_char(c: char) -> Result<Self> {
+impl TryFrom<char> for Tile {
+    type Error = CoreError;
+
+    fn try_from(c: char) -> Result<Self> {
         let tile = match c {
             '|' => Self::Vertical,
             '-' => Self::Horizontal,
@@ -252,6 +237,31 @@ impl Tile {
         };
         Ok(tile)
     }
+}
+
+impl Tile {
+    const PIPES: [Self; 6] = [
+        Self::Vertical,
+        Self::Horizontal,
+        Self::NorthWest,
+        Self::NorthEast,
+        Self::SouthWest,
+        Self::SouthEast,
+    ];
+
+    // In the same order as `Direction::cardinal`.
+    fn connections(&self) -> &'static [Direction] {
+        use Direction::*;
+        match self {
+            Self::Vertical => &[North, South],
+            Self::Horizontal => &[East, West],
+            Self::NorthEast => &[North, East],
+            Self::NorthWest => &[North, West],
+            Self::SouthWest => &[South, West],
+            Self::SouthEast => &[East, South],
+            Self::Ground | Self::Start => &[],
+        }
+    }
 
     fn to_display_char(self) -> char {
         match self {
@@ -267,65 +277,22 @@ impl Tile {
     }
 }
 
-fn calculate_start_tile(tiles: &[Tile], start_index: usize, grid: &Grid) -> Result<Tile> {
-    let compass_directions = [
-        Direction::North,
-        Direction::East,
-        Direction::South,
-        Direction::West,
-    ];
-    let neighbours: Vec<Tile> = compass_directions
+fn calculate_start_tile(tiles: &Grid<Tile>, start_index: usize) -> Result<Tile> {
+    // The start connects to whichever neighbours have a pipe pointing back.
+    let connections: Vec<Direction> = Direction::cardinal()
         .iter()
-        .map(|dir| {
-            if let Some(neighbour_idx) = grid.neighbour(start_index, *dir) {
-                let neighbour = tiles.get(neighbour_idx).unwrap_or(&Tile::Ground);
-                *neighbour
-            } else {
-                Tile::Ground
-            }
+        .filter(|dir| {
+            tiles
+                .neighbour(start_index, **dir)
+                .is_some_and(|idx| tiles[idx].connections().contains(&dir.opposite()))
         })
+        .copied()
         .collect();
 
-    let north = neighbours[0];
-    let east = neighbours[1];
-    let south = neighbours[2];
-    let west = neighbours[3];
-
-    let north_conn =
-        north == Tile::Vertical || north == Tile::SouthWest || north == Tile::SouthEast;
-    let east_conn = east == Tile::Horizontal || east == Tile::NorthWest || east == Tile::SouthWest;
-    let south_conn =
-        south == Tile::Vertical || south == Tile::NorthWest || south == Tile::NorthEast;
-    let west_conn = west == Tile::Horizontal || west == Tile::NorthEast || west == Tile::SouthEast;
-
-    let tile = match (north_conn, east_conn, south_conn, west_conn) {
-        (true, true, false, false) => Tile::NorthEast,
-        (true, false, true, false) => Tile::Vertical,
-        (true, false, false, true) => Tile::NorthWest,
-        (false, true, true, false) => Tile::SouthEast,
-        (false, true, false, true) => Tile::Horizontal,
-        (false, false, true, true) => Tile::SouthWest,
-        _ => {
-            return Err(CoreError::general("Couldn't work out start tile"));
-        }
-    };
-
-    Ok(tile)
-}
-
-fn tiles_to_string(tiles: &[Tile], width: usize) -> String {
-    tiles
-        .iter()
-        .enumerate()
-        .flat_map(|(i, tile)| {
-            let c = tile.to_display_char();
-            if (i + 1) % width == 0 {
-                vec![c, '\n']
-            } else {
-                vec![c]
-            }
-        })
-        .collect()
+    Tile::PIPES
+        .into_iter()
+        .find(|tile| tile.connections() == connections)
+        .ok_or_else(|| CoreError::general("Couldn't work out start tile"))
 }
 
 #[derive(Debug, Default, Clone)]
@@ -374,10 +341,10 @@ mod test {
             Tile::Ground, Tile::Start, Tile::Horizontal,
             Tile::Ground, Tile::Vertical, Tile::Ground,
         ];
-        let grid = Grid::new(3, 3);
+        let grid = Grid::from_cells(3, sample).unwrap();
 
         assert_eq!(
-            calculate_start_tile(&sample, 4, &grid).unwrap(),
+            calculate_start_tile(&grid, 4).unwrap(),
             Tile::SouthEast
         );
     }
@@ -419,14 +386,14 @@ mod test {
     #[test]
     fn can_build_maze_from_lines() {
         let maze = simple_maze();
-        assert_eq!(maze.grid.width(), 5);
-        assert_eq!(maze.grid.height(), 5);
+        assert_eq!(maze.tiles.width(), 5);
+        assert_eq!(maze.tiles.height(), 5);
         assert_eq!(maze.start_index, 6);
         let expected: Vec<Tile> = "......F-7..|.|..L-J......"
             .chars()
-            .map(|c| Tile::from_char(c).unwrap())
+            .map(|c| Tile::try_from(c).unwrap())
             .collect();
-        assert_eq!(maze.tiles, expected);
+        assert_eq!(maze.tiles.cells(), expected);
     }
 
     #[test]
@@ -446,9 +413,9 @@ mod test {
         let maze = complex_maze().solve().unwrap();
         let expected: Vec<Tile> = "..F7..FJ|.FJ.L7|F--JLJ..."
             .chars()
-            .map(|c| Tile::from_char(c).unwrap())
+            .map(|c| Tile::try_from(c).unwrap())
             .collect();
-        assert_eq!(maze.tiles, expected);
+        assert_eq!(maze.tiles.cells(), expected);
     }
 
     #[test]
@@ -459,4 +426,17 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn frames_show_the_maze_then_the_loop() -> Result<()> {
+        let mut solver = part_1();
+        for line in ["7-F7-", ".FJ|7", "SJLL7", "|F--J", "LJ.LJ"] {
+            solver.handle_line(line)?;
+        }
+        let frames = solver.visualize().unwrap().frames()?;
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], complex_maze().to_string());
+        assert_eq!(frames[1], complex_maze().solve()?.to_string());
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
-use crate::core::{Result, Solver};
-use crate::grid::{Grid, Point};
+use std::fmt;
+
+use crate::core::{Answer, Result, Solver, Visualize};
+use crate::grid::{Expansion, Grid, Point};
 
 pub fn part_1() -> Box<dyn Solver> {
     Box::new(Solution(UniverseBuilder::default(), 2))
@@ -17,10 +19,39 @@ impl Solver for Solution {
         self.0.add_line(line)
     }
 
-    fn extract_solution(&self) -> Result<String> {
+    fn extract_solution(&mut self) -> Result<Answer> {
+        let mut universe = self.0.build()?;
+        universe.expand(self.1);
+        Ok(universe.sum_of_shortest_paths().into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Cosmic Expansion")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Distances between galaxies in an expanding universe")
+    }
+
+    fn visualize(&self) -> Option<&dyn Visualize> {
+        Some(self)
+    }
+}
+
+// Anything bigger than this is too big to be worth drawing.
+const MAX_DRAWN_CELLS: usize = 250_000;
+
+// The universe before and, if it's still small enough to draw, after it's
+// expanded.
+impl Visualize for Solution {
+    fn frames(&self) -> Result<Vec<String>> {
         let mut universe = self.0.build()?;
+        let mut frames = vec![universe.to_string()];
         universe.expand(self.1);
-        Ok(universe.sum_of_shortest_paths().to_string())
+        if universe.grid.len() <= MAX_DRAWN_CELLS {
+            frames.push(universe.to_string());
+        }
+        Ok(frames)
     }
 }
 
@@ -32,49 +63,25 @@ struct Universe {
 
 impl Universe {
     fn expand(&mut self, factor: usize) {
-        let columns: Vec<usize> = (0..self.grid.width())
-            .filter(|x| !self.galaxies.iter().any(|p| p.x == *x))
-            .collect();
-        let rows: Vec<usize> = (0..self.grid.width())
-            .filter(|y| !self.galaxies.iter().any(|p| p.y == *y))
-            .collect();
-
         if factor == 0 {
             // ??
             return;
         }
 
-        let delta = factor - 1;
-
-        for x in columns.iter().rev() {
-            self.add_column(*x, delta);
-        }
-
-        for y in rows.iter().rev() {
-            self.add_row(*y, delta);
-        }
-
-        let grid = Grid::new(
-            (columns.len() * delta) + self.grid.width(),
-            (rows.len() * delta) + self.grid.height(),
-        );
-        self.grid = grid;
-    }
+        let columns: Vec<usize> = (0..self.grid.width())
+            .filter(|x| !self.galaxies.iter().any(|p| p.x == *x))
+            .collect();
+        let rows: Vec<usize> = (0..self.grid.height())
+            .filter(|y| !self.galaxies.iter().any(|p| p.y == *y))
+            .collect();
 
-    fn add_column(&mut self, x: usize, delta: usize) {
+        let expansion = Expansion::new(rows, columns, factor - 1);
         for galaxy in &mut self.galaxies {
-            if galaxy.x >= x {
-                galaxy.x += delta;
-            }
+            *galaxy = expansion.point(galaxy);
         }
-    }
 
-    fn add_row(&mut self, y: usize, delta: usize) {
-        for galaxy in &mut self.galaxies {
-            if galaxy.y >= y {
-                galaxy.y += delta;
-            }
-        }
+        let (width, height) = expansion.size(self.grid.width(), self.grid.height());
+        self.grid = Grid::new(width, height);
     }
 
     fn sum_of_shortest_paths(&self) -> usize {
@@ -92,64 +99,37 @@ impl Universe {
 }
 
 fn get_shortest_distance(p1: &Point, p2: &Point) -> usize {
-    let width = if p1.x > p2.x {
-        p1.x - p2.x
-    } else {
-        p2.x - p1.x
-    };
-    let height = if p1.y > p2.y {
-        p1.y - p2.y
-    } else {
-        p2.y - p1.y
-    };
+    let width = p1.x.abs_diff(p2.x);
+    let height = p1.y.abs_diff(p2.y);
     width + height
 }
 
-impl ToString for Universe {
-    fn to_string(&self) -> String {
-        let mut chars: Vec<char> = self.grid.indices().map(|_| '.').collect();
-        for point in self.galaxies.iter() {
-            let idx = self.grid.to_index(point);
-            chars[idx] = '#';
-        }
-        // wait, newlines!!!
-        let mut i = self.grid.len();
-        let width = self.grid.width();
-        loop {
-            chars.insert(i, '\n');
-            if i <= width {
-                break;
-            }
-            i -= width;
-        }
-        chars.iter().collect()
+impl fmt::Display for Universe {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let galaxies = self.galaxies.iter().map(|p| self.grid.to_index(p)).collect();
+        let rendered = self
+            .grid
+            .render_highlighted(&galaxies, |_, galaxy| if galaxy { '#' } else { '.' });
+        write!(f, "{}", rendered)
     }
 }
 
 #[derive(Debug, Default)]
-struct UniverseBuilder {
-    width: usize,
-    height: usize,
-    galaxies: Vec<Point>,
-}
+struct UniverseBuilder(Vec<String>);
 
 impl UniverseBuilder {
     fn add_line(&mut self, line: &str) -> Result<()> {
-        self.width = line.len();
-        let y = self.height;
-        for (x, c) in line.chars().enumerate() {
-            if c == '#' {
-                self.galaxies.push(Point { x, y });
-            }
-        }
-
-        self.height += 1;
+        self.0.push(line.to_string());
         Ok(())
     }
 
     fn build(&self) -> Result<Universe> {
-        let galaxies = self.galaxies.clone();
-        let grid = Grid::new(self.width, self.height);
+        let sky = Grid::<char>::from_lines(&self.0)?;
+        let galaxies = sky
+            .positions_of(|c| *c == '#')
+            .map(|position| Point::new(position.x, position.y))
+            .collect();
+        let grid = Grid::new(sky.width(), sky.height());
 
         Ok(Universe { grid, galaxies })
     }
@@ -269,4 +249,19 @@ mod test {
         assert_eq!(univ.sum_of_shortest_paths(), 1030);
         Ok(())
     }
+
+    #[test]
+    fn only_small_universes_are_drawn_expanded() -> Result<()> {
+        let frames = |mut solver: Box<dyn Solver>| -> Result<Vec<String>> {
+            for line in simple_universe()?.to_string().lines() {
+                solver.handle_line(line)?;
+            }
+            solver.visualize().unwrap().frames()
+        };
+        let expanded = frames(part_1())?;
+        assert_eq!(expanded.len(), 2);
+        assert_eq!(expanded[1], simple_universe_expanded()?.to_string());
+        assert_eq!(frames(part_2())?.len(), 1);
+        Ok(())
+    }
 }
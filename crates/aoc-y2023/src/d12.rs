@@ -0,0 +1,203 @@
+use crate::{
+    core::{Answer, CoreError, Result, Solver},
+    lru::{CacheStats, LruCache},
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(HotSprings::new(1))
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(HotSprings::new(5))
+}
+
+// Each row is worked out as it's read. The memo for a row is big enough to
+// hold every (spring, group) pair, so it never evicts; it's an `LruCache` for
+// the hit and miss counts in `--stats`.
+struct HotSprings {
+    unfold: usize,
+    total: u64,
+    cache: CacheStats,
+}
+
+impl HotSprings {
+    fn new(unfold: usize) -> Self {
+        Self {
+            unfold,
+            total: 0,
+            cache: CacheStats::default(),
+        }
+    }
+}
+
+impl Solver for HotSprings {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        let row = line.parse::<Row>()?.unfold(self.unfold);
+        let (arrangements, stats) = row.arrangements();
+        self.total += arrangements;
+        self.cache += stats;
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok(self.total.into())
+    }
+
+    fn stats(&self) -> Vec<(String, String)> {
+        vec![("memo".to_string(), self.cache.to_string())]
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Hot Springs")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Ways to fill in the unknown springs to match the damaged groups")
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Row {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl std::str::FromStr for Row {
+    type Err = CoreError;
+
+    fn from_str(line: &str) -> Result<Self> {
+        let (springs, groups) = line
+            .split_once(' ')
+            .ok_or_else(|| CoreError::general("Expected springs and groups"))?;
+        let springs = springs
+            .chars()
+            .map(|c| match c {
+                '.' => Ok(Spring::Operational),
+                '#' => Ok(Spring::Damaged),
+                '?' => Ok(Spring::Unknown),
+                _ => Err(CoreError::General(format!("Unknown spring {:?}", c))),
+            })
+            .collect::<Result<_>>()?;
+        let groups = groups
+            .split(',')
+            .map(|group| Ok(group.parse()?))
+            .collect::<Result<_>>()?;
+        Ok(Self { springs, groups })
+    }
+}
+
+impl Row {
+    fn unfold(&self, times: usize) -> Self {
+        let mut springs = self.springs.clone();
+        for _ in 1..times {
+            springs.push(Spring::Unknown);
+            springs.extend(&self.springs);
+        }
+        Self {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    fn arrangements(&self) -> (u64, CacheStats) {
+        let capacity = self.springs.len() * (self.groups.len() + 1);
+        let mut memo = LruCache::new(capacity.max(1));
+        let count = self.count(0, 0, &mut memo);
+        (count, memo.stats())
+    }
+
+    // Ways to place groups[group..] in springs[spring..].
+    fn count(&self, spring: usize, group: usize, memo: &mut LruCache<(usize, usize), u64>) -> u64 {
+        if spring >= self.springs.len() {
+            return (group == self.groups.len()) as u64;
+        }
+        if let Some(&count) = memo.get(&(spring, group)) {
+            return count;
+        }
+
+        let mut count = 0;
+        if self.springs[spring] != Spring::Damaged {
+            count += self.count(spring + 1, group, memo);
+        }
+        if self.springs[spring] != Spring::Operational && self.fits(spring, group) {
+            // Skip the spring after the group too, since it has to be
+            // operational.
+            let next = spring + self.groups[group] + 1;
+            count += self.count(next, group + 1, memo);
+        }
+
+        memo.insert((spring, group), count);
+        count
+    }
+
+    // Whether the next group can start at this spring.
+    fn fits(&self, spring: usize, group: usize) -> bool {
+        let Some(&length) = self.groups.get(group) else {
+            return false;
+        };
+        let end = spring + length;
+        end <= self.springs.len()
+            && !self.springs[spring..end].contains(&Spring::Operational)
+            && self.springs.get(end) != Some(&Spring::Damaged)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn arrangements(line: &str) -> u64 {
+        line.parse::<Row>().unwrap().arrangements().0
+    }
+
+    #[test]
+    fn can_parse_rows() -> Result<()> {
+        let row: Row = "#.? 1,1".parse()?;
+        assert_eq!(
+            row.springs,
+            vec![Spring::Damaged, Spring::Operational, Spring::Unknown]
+        );
+        assert_eq!(row.groups, vec![1, 1]);
+        assert!("#x? 1".parse::<Row>().is_err());
+        assert!("#.?".parse::<Row>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn can_count_arrangements() {
+        assert_eq!(arrangements("???.### 1,1,3"), 1);
+        assert_eq!(arrangements(".??..??...?##. 1,1,3"), 4);
+        assert_eq!(arrangements("?###???????? 3,2,1"), 10);
+        assert_eq!(arrangements("#.# 2"), 0);
+    }
+
+    #[test]
+    fn can_unfold_rows() -> Result<()> {
+        let row: Row = ".# 1".parse()?;
+        assert_eq!(row.unfold(3), ".#?.#?.# 1,1,1".parse()?);
+
+        let row: Row = "?###???????? 3,2,1".parse()?;
+        assert_eq!(row.unfold(5).arrangements().0, 506250);
+        Ok(())
+    }
+
+    #[test]
+    fn memo_stats_are_reported() -> Result<()> {
+        let mut solver = part_2();
+        solver.handle_line("?###???????? 3,2,1")?;
+        assert_eq!(solver.extract_solution()?, "506250");
+
+        let stats = solver.stats();
+        assert_eq!(stats[0].0, "memo");
+        assert!(stats[0].1.contains(", 0 evictions"));
+        assert!(!stats[0].1.starts_with("0 hits"));
+        Ok(())
+    }
+}
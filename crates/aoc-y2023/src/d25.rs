@@ -0,0 +1,135 @@
+use crate::{
+    core::{Answer, CoreError, Result, Solver},
+    graph::Graph,
+    rng::Rng,
+};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(Snowverload {
+        graph: Graph::new(),
+        seed: DEFAULT_SEED,
+    })
+}
+
+// There's no puzzle for part 2; the star comes free with the other 49.
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(MerryChristmas)
+}
+
+const WIRES_TO_CUT: usize = 3;
+
+const DEFAULT_SEED: u64 = 25;
+
+// Each random cut has a small chance of being the minimum one, but with a
+// clear bottleneck it's usually found within a few hundred attempts.
+const MAX_ATTEMPTS: usize = 100_000;
+
+#[derive(Debug)]
+struct Snowverload {
+    graph: Graph,
+    seed: u64,
+}
+
+impl Solver for Snowverload {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        let (component, connected) = line
+            .split_once(": ")
+            .ok_or_else(|| CoreError::general("Expected a component and its connections"))?;
+        for other in connected.split(' ') {
+            self.graph.add_edge(component, other);
+        }
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        let mut rng = Rng::new(self.seed);
+        for _ in 0..MAX_ATTEMPTS {
+            let cut = self.graph.random_cut(&mut rng);
+            if cut.edges.len() == WIRES_TO_CUT {
+                return Ok((cut.sizes.0 * cut.sizes.1).into());
+            }
+        }
+        let msg = format!(
+            "Couldn't find {} wires to cut in {} attempts",
+            WIRES_TO_CUT, MAX_ATTEMPTS
+        );
+        Err(CoreError::General(msg))
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Snowverload")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Three wires that split the components into two groups")
+    }
+
+    fn set_seed(&mut self, seed: u64) {
+        self.seed = seed;
+    }
+}
+
+#[derive(Debug)]
+struct MerryChristmas;
+
+impl Solver for MerryChristmas {
+    fn handle_line(&mut self, _line: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Ok("Merry Christmas!".into())
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        Some("Snowverload")
+    }
+
+    fn description(&self) -> Option<&'static str> {
+        Some("Push the big red button")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+
+    #[test]
+    fn can_parse_connections() -> Result<()> {
+        let mut solver = Snowverload {
+            graph: Graph::new(),
+            seed: DEFAULT_SEED,
+        };
+        solver.handle_line("jqt: rhn xhk nvd")?;
+        solver.handle_line("rhn: xhk")?;
+        assert_eq!(solver.graph.len(), 4);
+        assert_eq!(solver.graph.edges().len(), 4);
+        assert!(solver.handle_line("jqt rhn").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn any_seed_finds_the_same_cut() -> Result<()> {
+        for seed in [1, 2, 3] {
+            let mut solver = part_1();
+            solver.set_seed(seed);
+            solver.handle_input(EXAMPLE)?;
+            assert_eq!(solver.extract_solution()?, "54");
+        }
+        Ok(())
+    }
+}
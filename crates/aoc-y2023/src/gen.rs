@@ -0,0 +1,163 @@
+use crate::{
+    core::{CoreError, Day, Result},
+    rng::Rng,
+};
+
+// Random inputs for the days that have a generator, for `aoc gen` and the
+// tests below that compare implementations.
+pub fn generate(day: &Day, size: usize, seed: u64) -> Result<Vec<String>> {
+    let mut rng = Rng::new(seed);
+    let lines = match day.raw_value() {
+        3 => schematic(&mut rng, size),
+        5 => almanac(&mut rng, size),
+        7 => hands(&mut rng, size),
+        _ => {
+            let msg = format!("No input generator for 2023 day {}", day);
+            return Err(CoreError::general(&msg));
+        }
+    };
+    Ok(lines)
+}
+
+// Runs two implementations of the same calculation against a batch of
+// generated inputs and panics (with the offending seed) if they ever disagree.
+#[cfg(test)]
+pub(crate) fn assert_implementations_agree<T, F, G>(day: u16, size: usize, naive: F, optimized: G)
+where
+    T: PartialEq + std::fmt::Debug,
+    F: Fn(&[String]) -> T,
+    G: Fn(&[String]) -> T,
+{
+    let day: Day = day.to_string().parse().unwrap();
+
+    for seed in 0..20 {
+        let lines = generate(&day, size, seed).unwrap();
+        assert_eq!(
+            naive(&lines),
+            optimized(&lines),
+            "implementations disagree for day {} with size {} and seed {}",
+            day,
+            size,
+            seed
+        );
+    }
+}
+
+// A size x size engine schematic. Numbers have 1-3 digits and never touch
+// horizontally, so each run of digits is a single number.
+fn schematic(rng: &mut Rng, size: usize) -> Vec<String> {
+    let symbols = ['*', '#', '+', '$', '/', '@', '=', '%', '-', '&'];
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            while line.len() < size {
+                let remaining = size - line.len();
+                match rng.below(10) {
+                    0..=5 => line.push('.'),
+                    6 => line.push(rng.choose(&symbols)),
+                    _ => {
+                        let num_digits = rng.between(1, 3.min(remaining as u64));
+                        let low = 10_u64.pow(num_digits as u32 - 1);
+                        let high = 10_u64.pow(num_digits as u32) - 1;
+                        line.push_str(&rng.between(low, high).to_string());
+                        if line.len() < size {
+                            line.push('.');
+                        }
+                    }
+                }
+            }
+            line
+        })
+        .collect()
+}
+
+// An almanac with `size` seed numbers (so size / 2 ranges for part 2) and the
+// usual seven maps, each made of non-overlapping source ranges.
+fn almanac(rng: &mut Rng, size: usize) -> Vec<String> {
+    let limit: u64 = 4_000_000_000;
+    let num_seeds = size.max(2) & !1;
+
+    let seeds: Vec<String> = (0..num_seeds / 2)
+        .flat_map(|_| {
+            let length = rng.between(1, size.max(1) as u64);
+            let start = rng.below(limit - length);
+            [start.to_string(), length.to_string()]
+        })
+        .collect();
+
+    let mut lines = vec![format!("seeds: {}", seeds.join(" "))];
+
+    let names = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+    for pair in names.windows(2) {
+        lines.push(String::new());
+        lines.push(format!("{}-to-{} map:", pair[0], pair[1]));
+
+        let num_ranges = rng.between(2, 10);
+        let mut cuts: Vec<u64> = (0..num_ranges * 2).map(|_| rng.below(limit)).collect();
+        cuts.sort();
+        cuts.dedup();
+        for bounds in cuts.chunks_exact(2) {
+            let source_start = bounds[0];
+            let source_length = bounds[1] - bounds[0];
+            let destination_start = rng.below(limit - source_length);
+            lines.push(format!(
+                "{} {} {}",
+                destination_start, source_start, source_length
+            ));
+        }
+    }
+
+    lines
+}
+
+fn hands(rng: &mut Rng, size: usize) -> Vec<String> {
+    let labels = [
+        'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+    ];
+    (0..size)
+        .map(|_| {
+            let hand: String = (0..5).map(|_| rng.choose(&labels)).collect();
+            format!("{} {}", hand, rng.between(1, 1000))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_input() {
+        let day: Day = "7".parse().unwrap();
+        let first = generate(&day, 50, 42).unwrap();
+        let second = generate(&day, 50, 42).unwrap();
+        let third = generate(&day, 50, 43).unwrap();
+        assert_eq!(first, second);
+        assert_ne!(first, third);
+        assert_eq!(first.len(), 50);
+    }
+
+    #[test]
+    fn schematic_rows_have_requested_width() {
+        let mut rng = Rng::new(7);
+        let lines = schematic(&mut rng, 25);
+        assert_eq!(lines.len(), 25);
+        assert!(lines.iter().all(|line| line.len() == 25));
+    }
+
+    #[test]
+    fn empty_sizes_dont_panic() {
+        for day in ["3", "5", "7"] {
+            generate(&day.parse().unwrap(), 0, 1).unwrap();
+        }
+    }
+}
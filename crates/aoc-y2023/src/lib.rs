@@ -0,0 +1,38 @@
+use aoc_core::{checkpoint, core};
+use aoc_utils::{counter, graph, grid, lru, maths, rng, string_scanner};
+
+use crate::core::SolverFn;
+
+pub mod gen;
+
+// Generated by build_days.rs from the dNN.rs files in this directory.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+// Alternative implementations, selectable by name with --algo. The default
+// solver for a (day, part) is listed too so it can be named explicitly.
+pub const VARIANTS: &[(u16, u16, &str, SolverFn)] = &[
+    (5, 2, "ranges", d05::part_2),
+    (5, 2, "brute", d05::part_2_brute),
+    (7, 2, "counts", d07::part_2),
+    (7, 2, "enumerate", d07::part_2_enumerate),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{Day, Part, Result};
+
+    #[test]
+    fn generated_inputs_can_be_solved() -> Result<()> {
+        for day in ["3", "5", "7"] {
+            let day: Day = day.parse().unwrap();
+            let lines = gen::generate(&day, 40, 1)?;
+            for part in Part::all() {
+                let mut solver = get_solver(&day, &part)?;
+                solver.handle_input(&lines.join("\n"))?;
+                solver.extract_solution()?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+use aoc_core::core;
+
+// Generated by build_days.rs from the dNN.rs files in this directory. New
+// days can be started with `aoc scaffold 2024 <day>`.
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -0,0 +1,68 @@
+use std::{env, fs, io, path::Path};
+
+// Generates a year's day modules, SOLVERS table and `get_solver` from the
+// `dNN.rs` files in src, so a solver can't be written and then forgotten
+// about. Each module must provide `part_1` and `part_2`.
+fn main() -> io::Result<()> {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    // `aoc-y2023` -> `2023`
+    let package = env::var("CARGO_PKG_NAME").unwrap();
+    let year = package
+        .strip_prefix("aoc-y")
+        .expect("year crates are named aoc-yYYYY");
+    let src_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut days: Vec<(u16, String)> = fs::read_dir(&src_dir)?
+        .filter_map(|entry| day_module(&entry.ok()?.path()))
+        .collect();
+    days.sort();
+
+    // The generated file lives in OUT_DIR, so the modules need their paths
+    // spelled out.
+    let mut contents = String::new();
+    for (_, module) in &days {
+        let module_path = src_dir.join(format!("{}.rs", module));
+        contents.push_str(&format!("#[path = {:?}]\nmod {};\n", module_path, module));
+    }
+    contents.push_str("\npub const SOLVERS: &[(u16, u16, crate::core::SolverFn)] = &[\n");
+    for (day, module) in &days {
+        contents.push_str(&format!("    ({}, 1, {}::part_1),\n", day, module));
+        contents.push_str(&format!("    ({}, 2, {}::part_2),\n", day, module));
+    }
+    contents.push_str("];\n");
+    contents.push_str(&GET_SOLVER.replace("{year}", year));
+    fs::write(Path::new(&out_dir).join("days.rs"), contents)
+}
+
+const GET_SOLVER: &str = "
+pub fn get_solver(
+    day: &crate::core::Day,
+    part: &crate::core::Part,
+) -> crate::core::Result<Box<dyn crate::core::Solver>> {
+    let found = SOLVERS
+        .iter()
+        .find(|(d, p, _)| *d == day.raw_value() && *p == part.raw_value());
+    match found {
+        Some((_, _, solver)) => Ok(solver()),
+        None => Err(crate::core::CoreError::SolverNotImplemented {
+            year: crate::core::Year::new({year})?,
+            day: *day,
+            part: *part,
+        }),
+    }
+}
+";
+
+// `src/d07.rs` -> `(7, "d07")`
+fn day_module(path: &Path) -> Option<(u16, String)> {
+    if path.extension()? != "rs" {
+        return None;
+    }
+    let name = path.file_stem()?.to_str()?;
+    let digits = name.strip_prefix('d')?;
+    if digits.len() != 2 {
+        return None;
+    }
+    Some((digits.parse().ok()?, name.to_string()))
+}
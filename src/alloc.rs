@@ -0,0 +1,97 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+// Wraps the system allocator, keeping counts that `measure` can report on.
+// Counts are process-wide, so anything running on other threads is included.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MemoryStats {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    pub peak: usize,
+}
+
+impl fmt::Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "allocations: {} ({} bytes)",
+            self.allocations, self.bytes_allocated
+        )?;
+        write!(f, "peak heap: {} bytes", self.peak)
+    }
+}
+
+// Peak is relative to whatever was already allocated when `f` started.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, MemoryStats) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = MemoryStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes_allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+    };
+    (result, stats)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counts_allocations() {
+        let (len, stats) = measure(|| {
+            let v: Vec<u64> = Vec::with_capacity(1000);
+            v.capacity()
+        });
+        assert_eq!(len, 1000);
+        assert!(stats.allocations >= 1);
+        assert!(stats.bytes_allocated >= 8000);
+        assert!(stats.peak >= 8000);
+    }
+}
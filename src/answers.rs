@@ -0,0 +1,66 @@
+use std::{collections::BTreeMap, fs, path::Path, str::FromStr};
+
+use crate::core::{CoreError, Day, Part, Result, Year};
+
+// Known-correct answers, keyed by year then day, e.g.
+//
+//   [2023.7]
+//   part1 = 6440
+//   part2 = "5905"
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, toml::Value>>>);
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|e| {
+            let msg = format!("Couldn't read answers from {}: {}", path.display(), e);
+            CoreError::General(msg)
+        })?;
+        contents.parse()
+    }
+
+    pub fn get(&self, year: &Year, day: &Day, part: &Part) -> Option<String> {
+        let value = self
+            .0
+            .get(&year.raw_value().to_string())?
+            .get(&day.raw_value().to_string())?
+            .get(&format!("part{}", part.raw_value()))?;
+        match value {
+            toml::Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        toml::from_str(s)
+            .map(Self)
+            .map_err(|e| CoreError::General(format!("Invalid answers file: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_look_up_answers() {
+        let answers: Answers = "[2023.7]\npart1 = 6440\npart2 = \"5905\"\n"
+            .parse()
+            .unwrap();
+        let year: Year = "2023".parse().unwrap();
+        let day: Day = "7".parse().unwrap();
+
+        let part1 = answers.get(&year, &day, &"1".parse().unwrap());
+        assert_eq!(part1, Some("6440".to_string()));
+        let part2 = answers.get(&year, &day, &"2".parse().unwrap());
+        assert_eq!(part2, Some("5905".to_string()));
+        let other_day = answers.get(&year, &"8".parse().unwrap(), &"1".parse().unwrap());
+        assert_eq!(other_day, None);
+
+        assert!("[2023".parse::<Answers>().is_err());
+    }
+}
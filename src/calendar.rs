@@ -0,0 +1,84 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Puzzles unlock at midnight EST (UTC-5), all year round.
+const UNLOCK_OFFSET_SECS: i64 = -5 * 60 * 60;
+
+// The (year, day) of the most recently unlocked puzzle, if it's currently
+// December 1st-25th in AoC time.
+pub fn puzzle_on(unix_secs: i64) -> Option<(u16, u16)> {
+    let days = (unix_secs + UNLOCK_OFFSET_SECS).div_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    if month == 12 && day <= 25 {
+        Some((year as u16, day as u16))
+    } else {
+        None
+    }
+}
+
+pub fn puzzle_today() -> Option<(u16, u16)> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?;
+    puzzle_on(now.as_secs() as i64)
+}
+
+// Formats a timestamp in AoC time, e.g. "2023-12-01 00:00:00".
+pub fn format_aoc_time(unix_secs: i64) -> String {
+    let secs = unix_secs + UNLOCK_OFFSET_SECS;
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let time = secs.rem_euclid(86_400);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+// Days since 1970-01-01 to a (year, month, day) date; see
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_convert_days_to_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(19_692), (2023, 12, 1));
+        assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn puzzles_unlock_at_midnight_est() {
+        // 2023-12-01T05:00:00Z is midnight EST.
+        let unlock = 1_701_406_800;
+        assert_eq!(puzzle_on(unlock - 1), None);
+        assert_eq!(puzzle_on(unlock), Some((2023, 1)));
+        assert_eq!(puzzle_on(unlock + 24 * 86_400), Some((2023, 25)));
+        assert_eq!(puzzle_on(unlock + 25 * 86_400), None);
+    }
+
+    #[test]
+    fn formats_times_in_est() {
+        assert_eq!(format_aoc_time(1_701_406_800), "2023-12-01 00:00:00");
+        assert_eq!(
+            format_aoc_time(1_701_406_800 + 3_723),
+            "2023-12-01 01:02:03"
+        );
+        assert_eq!(format_aoc_time(1_701_406_799), "2023-11-30 23:59:59");
+    }
+}
@@ -0,0 +1,92 @@
+use crate::core::SolutionReport;
+
+pub struct Comparison {
+    pub lines: Vec<String>,
+    pub differences: usize,
+}
+
+// Both sides solve the same puzzles in the same order, so reports are
+// compared pairwise rather than with a full diff algorithm. Only answers
+// count as differences; timings are shown for information.
+pub fn compare(
+    labels: (&str, &str),
+    left: &[SolutionReport],
+    right: &[SolutionReport],
+    timings: bool,
+) -> Comparison {
+    let mut lines = vec![format!("--- {}", labels.0), format!("+++ {}", labels.1)];
+    let mut differences = 0;
+
+    for (l, r) in left.iter().zip(right) {
+        let (l_line, r_line) = (describe(l, timings), describe(r, timings));
+        if l.answer != r.answer {
+            differences += 1;
+        }
+        if l_line == r_line {
+            lines.push(format!(" {}", l_line));
+        } else {
+            lines.push(format!("-{}", l_line));
+            lines.push(format!("+{}", r_line));
+        }
+    }
+
+    Comparison { lines, differences }
+}
+
+fn describe(report: &SolutionReport, timings: bool) -> String {
+    let line = format!(
+        "{} day {} part {}: {}",
+        report.year, report.day, report.part, report.answer
+    );
+    match timings {
+        true => format!("{} ({:.3}ms)", line, report.elapsed.as_secs_f64() * 1000.0),
+        false => line,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    fn report(part: u16, answer: u64, micros: u64) -> SolutionReport {
+        SolutionReport {
+            year: 2023,
+            day: 5,
+            part,
+            title: None,
+            answer: answer.into(),
+            elapsed: Duration::from_micros(micros),
+        }
+    }
+
+    #[test]
+    fn can_compare_answers() {
+        let left = [report(1, 35, 100), report(2, 46, 9_000)];
+        let right = [report(1, 35, 200), report(2, 47, 300)];
+
+        let comparison = compare(("brute", "ranges"), &left, &right, false);
+        assert_eq!(comparison.differences, 1);
+        assert_eq!(
+            comparison.lines,
+            [
+                "--- brute",
+                "+++ ranges",
+                " 2023 day 5 part 1: 35",
+                "-2023 day 5 part 2: 46",
+                "+2023 day 5 part 2: 47",
+            ]
+        );
+    }
+
+    #[test]
+    fn timings_are_shown_but_not_counted() {
+        let left = [report(1, 35, 100)];
+        let right = [report(1, 35, 200)];
+
+        let comparison = compare(("a", "b"), &left, &right, true);
+        assert_eq!(comparison.differences, 0);
+        assert_eq!(comparison.lines[2], "-2023 day 5 part 1: 35 (0.100ms)");
+        assert_eq!(comparison.lines[3], "+2023 day 5 part 1: 35 (0.200ms)");
+    }
+}
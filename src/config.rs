@@ -0,0 +1,128 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    core::{CoreError, Result, Year},
+    output::OutputFormat,
+};
+
+// Defaults for flags that would otherwise be retyped on every invocation.
+// Command-line flags take precedence, then environment variables, then the
+// config file.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub inputs_dir: Option<PathBuf>,
+    pub session_file: Option<PathBuf>,
+    pub year: Option<u16>,
+    pub format: Option<OutputFormat>,
+
+    // Only ever set from AOC_SESSION, so tokens don't end up in config files.
+    #[serde(skip)]
+    pub session: Option<String>,
+}
+
+impl Config {
+    // `./aoc.toml` wins over `~/.config/aoc/config.toml`; with neither, every
+    // setting falls back to its built-in default.
+    pub fn load() -> Result<Self> {
+        let mut candidates = vec![PathBuf::from("aoc.toml")];
+        if let Ok(dir) = config_dir() {
+            candidates.push(dir.join("config.toml"));
+        }
+
+        let config = match candidates.iter().find(|path| path.exists()) {
+            Some(path) => Self::from_file(path)?,
+            None => Self::default(),
+        };
+        Ok(config.with_env(|name| env::var(name).ok()))
+    }
+
+    pub fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Self {
+        if let Some(dir) = var("AOC_INPUT_DIR").filter(|s| !s.is_empty()) {
+            self.inputs_dir = Some(PathBuf::from(dir));
+        }
+        if let Some(session) = var("AOC_SESSION").filter(|s| !s.is_empty()) {
+            self.session = Some(session);
+        }
+        self
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        toml::from_str(&contents).map_err(|e| {
+            let msg = format!("Invalid config in {}: {}", path.display(), e);
+            CoreError::General(msg)
+        })
+    }
+
+    pub fn inputs_dir(&self) -> PathBuf {
+        self.inputs_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(".").join("inputs"))
+    }
+
+    pub fn year(&self, year: Option<Year>) -> Result<Year> {
+        match (year, self.year) {
+            (Some(year), _) => Ok(year),
+            (None, Some(year)) => Ok(year.to_string().parse()?),
+            (None, None) => Err(CoreError::general(
+                "No year given and no default year in the config",
+            )),
+        }
+    }
+}
+
+pub fn config_dir() -> Result<PathBuf> {
+    let home = env::var("HOME").map_err(|_| CoreError::general("HOME is not set"))?;
+    Ok(PathBuf::from(home).join(".config").join("aoc"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn can_parse_config() {
+        let config: Config =
+            toml::from_str("inputs_dir = \"/tmp/aoc\"\nyear = 2023\nformat = \"json\"\n").unwrap();
+        assert_eq!(config.inputs_dir(), PathBuf::from("/tmp/aoc"));
+        assert_eq!(config.session_file, None);
+        assert_eq!(config.format, Some(OutputFormat::Json));
+        assert_eq!(config.year(None).unwrap().raw_value(), 2023);
+
+        assert!(toml::from_str::<Config>("colour = true").is_err());
+    }
+
+    #[test]
+    fn env_overrides_config_file() {
+        let config: Config = toml::from_str("inputs_dir = \"from-file\"").unwrap();
+        let config = config.with_env(|name| match name {
+            "AOC_INPUT_DIR" => Some("/data/aoc".to_string()),
+            "AOC_SESSION" => Some("abc123".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.inputs_dir(), PathBuf::from("/data/aoc"));
+        assert_eq!(config.session.as_deref(), Some("abc123"));
+
+        let config = Config::default().with_env(|_| Some(String::new()));
+        assert_eq!(config.inputs_dir(), PathBuf::from("./inputs"));
+        assert_eq!(config.session, None);
+    }
+
+    #[test]
+    fn flags_override_config() {
+        let config = Config {
+            year: Some(1999),
+            ..Config::default()
+        };
+        assert!(config.year(None).is_err());
+        let year: Year = "2023".parse().unwrap();
+        assert_eq!(config.year(Some(year)).unwrap(), year);
+        assert_eq!(Config::default().inputs_dir(), PathBuf::from("./inputs"));
+    }
+}
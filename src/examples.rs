@@ -0,0 +1,101 @@
+pub fn example(year: u16, day: u16, part: u16) -> Option<&'static str> {
+    let text = match (year, day, part) {
+        (2015, 1, 1) => include_str!("../inputs/examples/201501-1.txt"),
+        (2015, 1, 2) => include_str!("../inputs/examples/201501-2.txt"),
+        (2015, 2, 1) => include_str!("../inputs/examples/201502-1.txt"),
+        (2015, 2, 2) => include_str!("../inputs/examples/201502-2.txt"),
+        (2015, 3, 1) => include_str!("../inputs/examples/201503-1.txt"),
+        (2015, 3, 2) => include_str!("../inputs/examples/201503-2.txt"),
+        (2015, 4, 1) => include_str!("../inputs/examples/201504-1.txt"),
+        (2015, 4, 2) => include_str!("../inputs/examples/201504-2.txt"),
+        (2015, 5, 1) => include_str!("../inputs/examples/201505-1.txt"),
+        (2015, 5, 2) => include_str!("../inputs/examples/201505-2.txt"),
+        (2022, 1, 1) => include_str!("../inputs/examples/202201-1.txt"),
+        (2022, 1, 2) => include_str!("../inputs/examples/202201-2.txt"),
+        (2022, 2, 1) => include_str!("../inputs/examples/202202-1.txt"),
+        (2022, 2, 2) => include_str!("../inputs/examples/202202-2.txt"),
+        (2022, 3, 1) => include_str!("../inputs/examples/202203-1.txt"),
+        (2022, 3, 2) => include_str!("../inputs/examples/202203-2.txt"),
+        (2023, 1, 1) => include_str!("../inputs/examples/202301-1.txt"),
+        (2023, 1, 2) => include_str!("../inputs/examples/202301-2.txt"),
+        (2023, 2, 1) => include_str!("../inputs/examples/202302-1.txt"),
+        (2023, 2, 2) => include_str!("../inputs/examples/202302-2.txt"),
+        (2023, 3, 1) => include_str!("../inputs/examples/202303-1.txt"),
+        (2023, 3, 2) => include_str!("../inputs/examples/202303-2.txt"),
+        (2023, 4, 1) => include_str!("../inputs/examples/202304-1.txt"),
+        (2023, 4, 2) => include_str!("../inputs/examples/202304-2.txt"),
+        (2023, 5, 1) => include_str!("../inputs/examples/202305-1.txt"),
+        (2023, 5, 2) => include_str!("../inputs/examples/202305-2.txt"),
+        (2023, 6, 1) => include_str!("../inputs/examples/202306-1.txt"),
+        (2023, 6, 2) => include_str!("../inputs/examples/202306-2.txt"),
+        (2023, 7, 1) => include_str!("../inputs/examples/202307-1.txt"),
+        (2023, 7, 2) => include_str!("../inputs/examples/202307-2.txt"),
+        (2023, 8, 1) => include_str!("../inputs/examples/202308-1.txt"),
+        (2023, 8, 2) => include_str!("../inputs/examples/202308-2.txt"),
+        (2023, 9, 1) => include_str!("../inputs/examples/202309-1.txt"),
+        (2023, 9, 2) => include_str!("../inputs/examples/202309-2.txt"),
+        (2023, 10, 1) => include_str!("../inputs/examples/202310-1.txt"),
+        (2023, 10, 2) => include_str!("../inputs/examples/202310-2.txt"),
+        (2023, 11, 1) => include_str!("../inputs/examples/202311-1.txt"),
+        (2023, 11, 2) => include_str!("../inputs/examples/202311-2.txt"),
+        (2023, 12, 1) => include_str!("../inputs/examples/202312-1.txt"),
+        (2023, 12, 2) => include_str!("../inputs/examples/202312-2.txt"),
+        (2023, 25, 1) => include_str!("../inputs/examples/202325-1.txt"),
+        (2023, 25, 2) => include_str!("../inputs/examples/202325-2.txt"),
+        _ => {
+            return None;
+        }
+    };
+    Some(text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::runner;
+
+    #[test]
+    fn examples_give_the_published_answers() {
+        // 2015 day 4 is missing since it takes too long to mine without
+        // optimisations.
+        let expected = [
+            ("2015", "1", "3", "5"),
+            ("2015", "2", "101", "48"),
+            ("2015", "3", "4", "11"),
+            ("2015", "5", "2", "2"),
+            ("2022", "1", "24000", "45000"),
+            ("2022", "2", "15", "12"),
+            ("2022", "3", "157", "70"),
+            ("2023", "1", "142", "281"),
+            ("2023", "2", "8", "2286"),
+            ("2023", "3", "4361", "467835"),
+            ("2023", "4", "13", "30"),
+            ("2023", "5", "35", "46"),
+            ("2023", "6", "288", "71503"),
+            ("2023", "7", "6440", "5905"),
+            ("2023", "8", "2", "6"),
+            ("2023", "9", "114", "2"),
+            ("2023", "10", "8", "4"),
+            ("2023", "11", "374", "82000210"),
+            ("2023", "12", "21", "525152"),
+            ("2023", "25", "54", "Merry Christmas!"),
+        ];
+
+        for (year, day, answer_1, answer_2) in expected {
+            for (part, answer) in [("1", answer_1), ("2", answer_2)] {
+                let year = year.parse().unwrap();
+                let day = day.parse().unwrap();
+                let part = part.parse().unwrap();
+                let mut solver = runner::get_solver(&year, &day, &part).unwrap();
+                let text = example(year.raw_value(), day.raw_value(), part.raw_value()).unwrap();
+                solver.handle_input(text).unwrap();
+                assert_eq!(solver.extract_solution().unwrap(), answer);
+            }
+        }
+    }
+
+    #[test]
+    fn missing_examples_are_none() {
+        assert!(example(2023, 13, 1).is_none());
+    }
+}
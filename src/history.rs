@@ -0,0 +1,208 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{CoreError, Result, SolutionReport};
+
+// One line of the history file per solver run.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u16,
+    pub part: u16,
+    pub answer: String,
+    pub elapsed_us: u64,
+    // The variant that was run, if it wasn't the default solver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algo: Option<String>,
+}
+
+impl HistoryEntry {
+    pub fn new(report: &SolutionReport, timestamp: u64, algo: Option<&str>) -> Self {
+        Self {
+            timestamp,
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            answer: report.answer.to_string(),
+            elapsed_us: report.elapsed.as_micros() as u64,
+            algo: algo.map(String::from),
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_micros(self.elapsed_us)
+    }
+}
+
+pub fn default_path() -> Result<PathBuf> {
+    let dir = match env::var("XDG_DATA_HOME") {
+        Ok(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => {
+            let home = env::var("HOME").map_err(|_| CoreError::general("HOME is not set"))?;
+            PathBuf::from(home).join(".local").join("share")
+        }
+    };
+    Ok(dir.join("aoc").join("history.jsonl"))
+}
+
+pub fn record(path: &Path, reports: &[SolutionReport], algo: Option<&str>) -> Result<()> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    for report in reports {
+        let line = serde_json::to_string(&HistoryEntry::new(report, timestamp, algo))
+            .map_err(|e| CoreError::General(e.to_string()))?;
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+// Lines that don't parse (e.g. from an interrupted write) are skipped.
+pub fn load(path: &Path) -> Result<Vec<HistoryEntry>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let contents = fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RunSummary {
+    pub day: u16,
+    pub part: u16,
+    pub algo: Option<String>,
+    pub runs: usize,
+    pub first: Duration,
+    pub best: Duration,
+    pub latest: Duration,
+}
+
+impl RunSummary {
+    // Percentage change of the latest run relative to the first one.
+    pub fn trend(&self) -> f64 {
+        let first = self.first.as_secs_f64();
+        if first == 0.0 {
+            return 0.0;
+        }
+        (self.latest.as_secs_f64() - first) / first * 100.0
+    }
+}
+
+// Each variant is summarised separately, since their timings aren't
+// comparable with each other.
+pub fn summarise(entries: &[HistoryEntry], year: u16) -> Vec<RunSummary> {
+    let mut by_puzzle: BTreeMap<(u16, u16, Option<&str>), Vec<&HistoryEntry>> = BTreeMap::new();
+    for entry in entries.iter().filter(|e| e.year == year) {
+        by_puzzle
+            .entry((entry.day, entry.part, entry.algo.as_deref()))
+            .or_default()
+            .push(entry);
+    }
+
+    by_puzzle
+        .into_iter()
+        .map(|((day, part, algo), mut runs)| {
+            runs.sort_by_key(|e| e.timestamp);
+            RunSummary {
+                day,
+                part,
+                algo: algo.map(String::from),
+                runs: runs.len(),
+                first: runs[0].elapsed(),
+                best: runs.iter().map(|e| e.elapsed()).min().unwrap_or_default(),
+                latest: runs[runs.len() - 1].elapsed(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entry(timestamp: u64, day: u16, elapsed_us: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            year: 2023,
+            day,
+            part: 1,
+            answer: "42".to_string(),
+            elapsed_us,
+            algo: None,
+        }
+    }
+
+    #[test]
+    fn can_summarise_runs() {
+        let entries = vec![
+            entry(3, 5, 500),
+            entry(1, 5, 2000),
+            entry(2, 5, 400),
+            entry(1, 1, 10),
+        ];
+        let summary = summarise(&entries, 2023);
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].day, 1);
+
+        let d05 = &summary[1];
+        assert_eq!(d05.runs, 3);
+        assert_eq!(d05.first, Duration::from_micros(2000));
+        assert_eq!(d05.best, Duration::from_micros(400));
+        assert_eq!(d05.latest, Duration::from_micros(500));
+        assert_eq!(d05.trend(), -75.0);
+
+        assert!(summarise(&entries, 2022).is_empty());
+    }
+
+    #[test]
+    fn summarises_each_algo_separately() {
+        let mut naive = entry(2, 5, 9000);
+        naive.algo = Some("naive".to_string());
+        let entries = vec![entry(1, 5, 500), naive, entry(3, 5, 400)];
+        let summary = summarise(&entries, 2023);
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].algo, None);
+        assert_eq!(summary[0].runs, 2);
+        assert_eq!(summary[0].best, Duration::from_micros(400));
+        assert_eq!(summary[1].algo.as_deref(), Some("naive"));
+        assert_eq!(summary[1].best, Duration::from_micros(9000));
+    }
+
+    #[test]
+    fn can_record_and_load() {
+        let path = env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let report = SolutionReport {
+            year: 2023,
+            day: 7,
+            part: 2,
+            title: None,
+            answer: 5905u64.into(),
+            elapsed: Duration::from_micros(1234),
+        };
+        record(&path, &[report.clone(), report], Some("fast")).unwrap();
+
+        let entries = load(&path).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].answer, "5905");
+        assert_eq!(entries[0].elapsed(), Duration::from_micros(1234));
+        assert_eq!(entries[0].algo.as_deref(), Some("fast"));
+        fs::remove_file(&path).unwrap();
+    }
+}
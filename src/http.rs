@@ -0,0 +1,263 @@
+use std::{
+    fmt, fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    config::config_dir,
+    core::{CoreError, Day, Part, Result, Year},
+};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const MIN_INTERVAL: Duration = Duration::from_secs(5);
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (github.com/sujaymansingh/adventofcode)"
+);
+
+pub struct AocClient {
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new().user_agent(USER_AGENT).build();
+        Self {
+            session: session.trim().to_string(),
+            agent,
+        }
+    }
+
+    pub fn from_session_file(path: Option<PathBuf>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path,
+            None => default_session_file()?,
+        };
+        let session = fs::read_to_string(&path).map_err(|e| {
+            let msg = format!("Couldn't read session from {}: {}", path.display(), e);
+            CoreError::General(msg)
+        })?;
+        Ok(Self::new(&session))
+    }
+
+    pub fn get_input(&self, year: &Year, day: &Day) -> Result<String> {
+        self.get(&input_url(year, day))
+    }
+
+    pub fn get_puzzle(&self, year: &Year, day: &Day) -> Result<String> {
+        self.get(&puzzle_url(year, day))
+    }
+
+    pub fn get_leaderboard(&self, year: &Year, id: u64) -> Result<String> {
+        self.get(&leaderboard_url(year, id))
+    }
+
+    pub fn submit_answer(
+        &self,
+        year: &Year,
+        day: &Day,
+        part: &Part,
+        answer: &str,
+    ) -> Result<SubmissionStatus> {
+        let level = part.raw_value().to_string();
+        let html = self.post(
+            &answer_url(year, day),
+            &[("level", level.as_str()), ("answer", answer)],
+        )?;
+        Ok(SubmissionStatus::from_html(&html))
+    }
+
+    pub fn get(&self, url: &str) -> Result<String> {
+        wait_politely();
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(http_error)?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String> {
+        wait_politely();
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form)
+            .map_err(http_error)?;
+        Ok(response.into_string()?)
+    }
+}
+
+// aoc-core doesn't depend on ureq, so its errors are carried as text.
+fn http_error(error: ureq::Error) -> CoreError {
+    CoreError::Http(error.to_string())
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SubmissionStatus {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    Wait(String),
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl SubmissionStatus {
+    pub fn from_html(html: &str) -> Self {
+        if html.contains("That's the right answer") {
+            Self::Correct
+        } else if html.contains("That's not the right answer") {
+            if html.contains("your answer is too high") {
+                Self::TooHigh
+            } else if html.contains("your answer is too low") {
+                Self::TooLow
+            } else {
+                Self::Incorrect
+            }
+        } else if html.contains("You gave an answer too recently") {
+            let wait = html
+                .split("You have ")
+                .nth(1)
+                .and_then(|rest| rest.split(" left to wait").next())
+                .unwrap_or("some time");
+            Self::Wait(wait.to_string())
+        } else if html.contains("Did you already complete it") {
+            Self::AlreadySolved
+        } else {
+            Self::Unknown(html.to_string())
+        }
+    }
+}
+
+impl fmt::Display for SubmissionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "Right answer!"),
+            Self::TooHigh => write!(f, "Wrong answer: too high"),
+            Self::TooLow => write!(f, "Wrong answer: too low"),
+            Self::Incorrect => write!(f, "Wrong answer"),
+            Self::Wait(wait) => write!(f, "Submitted too recently, wait {}", wait),
+            Self::AlreadySolved => write!(f, "Already solved (or not unlocked yet)"),
+            Self::Unknown(html) => write!(f, "Unrecognised response:\n{}", html),
+        }
+    }
+}
+
+fn answer_url(year: &Year, day: &Day) -> String {
+    format!(
+        "{}/{}/day/{}/answer",
+        BASE_URL,
+        year.raw_value(),
+        day.raw_value()
+    )
+}
+
+fn puzzle_url(year: &Year, day: &Day) -> String {
+    format!("{}/{}/day/{}", BASE_URL, year.raw_value(), day.raw_value())
+}
+
+fn input_url(year: &Year, day: &Day) -> String {
+    format!(
+        "{}/{}/day/{}/input",
+        BASE_URL,
+        year.raw_value(),
+        day.raw_value()
+    )
+}
+
+fn leaderboard_url(year: &Year, id: u64) -> String {
+    format!(
+        "{}/{}/leaderboard/private/view/{}.json",
+        BASE_URL,
+        year.raw_value(),
+        id
+    )
+}
+
+fn default_session_file() -> Result<PathBuf> {
+    Ok(config_dir()?.join("session"))
+}
+
+// The time of the last request is kept on disk so that separate invocations
+// (e.g. fetching several days in a shell loop) are still spaced out.
+fn wait_politely() {
+    let state_file = match config_dir() {
+        Ok(dir) => dir.join("last_request"),
+        Err(_) => return,
+    };
+
+    let last_request = fs::read_to_string(&state_file)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok());
+    if let Some(wait) = time_to_wait(last_request, now_millis()) {
+        thread::sleep(wait);
+    }
+
+    let _ = fs::create_dir_all(state_file.parent().unwrap());
+    let _ = fs::write(&state_file, now_millis().to_string());
+}
+
+fn time_to_wait(last_request_millis: Option<u64>, now_millis: u64) -> Option<Duration> {
+    let elapsed = Duration::from_millis(now_millis.saturating_sub(last_request_millis?));
+    MIN_INTERVAL.checked_sub(elapsed).filter(|d| !d.is_zero())
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn builds_input_url() {
+        let url = input_url(&"2023".parse().unwrap(), &"7".parse().unwrap());
+        assert_eq!(url, "https://adventofcode.com/2023/day/7/input");
+    }
+
+    #[test]
+    fn requests_are_spaced_out() {
+        assert_eq!(time_to_wait(None, 10_000), None);
+        assert_eq!(time_to_wait(Some(1_000), 10_000), None);
+        assert_eq!(
+            time_to_wait(Some(8_000), 10_000),
+            Some(Duration::from_secs(3))
+        );
+    }
+
+    #[test]
+    fn can_parse_submission_responses() {
+        let parse = SubmissionStatus::from_html;
+        assert_eq!(
+            parse("<p>That's the right answer!  You are one gold star closer"),
+            SubmissionStatus::Correct
+        );
+        assert_eq!(
+            parse("<p>That's not the right answer; your answer is too high."),
+            SubmissionStatus::TooHigh
+        );
+        assert_eq!(
+            parse("<p>That's not the right answer.  If you're stuck"),
+            SubmissionStatus::Incorrect
+        );
+        assert_eq!(
+            parse("<p>You gave an answer too recently; ...  You have 1m 12s left to wait."),
+            SubmissionStatus::Wait("1m 12s".to_string())
+        );
+        assert_eq!(
+            parse("<p>You don't seem to be solving the right level.  Did you already complete it?"),
+            SubmissionStatus::AlreadySolved
+        );
+    }
+}
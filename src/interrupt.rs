@@ -0,0 +1,30 @@
+use std::{sync::OnceLock, thread, time::Duration};
+
+use signal_hook::{consts::SIGINT, flag};
+
+use crate::core::Cancellation;
+
+// The first Ctrl-C asks the running solver to stop, so it can say how far it
+// got; a second one exits straight away, for solvers that never check.
+pub fn cancellation() -> &'static Cancellation {
+    static CANCELLATION: OnceLock<Cancellation> = OnceLock::new();
+    CANCELLATION.get_or_init(|| {
+        let cancellation = Cancellation::default();
+        // The conditional shutdown has to be registered first, so that it
+        // only fires if the flag was already set by an earlier Ctrl-C.
+        let registered = flag::register_conditional_shutdown(SIGINT, 130, cancellation.flag())
+            .and_then(|_| flag::register(SIGINT, cancellation.flag()));
+        if let Err(e) = registered {
+            tracing::warn!("Couldn't install Ctrl-C handler: {}", e);
+        }
+
+        let watched = cancellation.clone();
+        thread::spawn(move || {
+            while !watched.is_cancelled() {
+                thread::sleep(Duration::from_millis(50));
+            }
+            eprintln!("\nInterrupting... press Ctrl-C again to quit immediately");
+        });
+        cancellation
+    })
+}
@@ -0,0 +1,228 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    calendar::format_aoc_time,
+    core::{CoreError, Day, Result},
+};
+
+// AoC asks that private leaderboards aren't fetched more often than this.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Member {
+    id: u64,
+    name: Option<String>,
+    stars: u32,
+    local_score: u32,
+    last_star_ts: i64,
+    #[serde(default)]
+    completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Star {
+    get_star_ts: i64,
+}
+
+impl Member {
+    fn name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    fn star_time(&self, day: u16, part: u16) -> Option<i64> {
+        let day = self.completion_day_level.get(&day.to_string())?;
+        day.get(&part.to_string()).map(|star| star.get_star_ts)
+    }
+
+    // One character per day: `*` for both stars, `+` for one, `.` for none.
+    fn days(&self) -> String {
+        Day::all()
+            .map(|day| {
+                match (
+                    self.star_time(day.raw_value(), 1),
+                    self.star_time(day.raw_value(), 2),
+                ) {
+                    (Some(_), Some(_)) => '*',
+                    (Some(_), None) => '+',
+                    _ => '.',
+                }
+            })
+            .collect()
+    }
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|e| CoreError::General(format!("Couldn't parse leaderboard: {}", e)))
+    }
+
+    // Highest score first; ties go to whoever got their last star earlier.
+    fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (u32::MAX - m.local_score, m.last_star_ts, m.id));
+        members
+    }
+
+    pub fn render(&self) -> String {
+        let mut lines = vec![format!(
+            "Rank  Score  Stars  {:25}  {:19}  Name",
+            "Days", "Last star"
+        )];
+        for (i, member) in self.ranked().iter().enumerate() {
+            let last_star = match member.last_star_ts {
+                0 => "-".to_string(),
+                ts => format_aoc_time(ts),
+            };
+            lines.push(format!(
+                "{:>4}  {:>5}  {:>5}  {}  {:19}  {}",
+                i + 1,
+                member.local_score,
+                member.stars,
+                member.days(),
+                last_star,
+                member.name()
+            ));
+        }
+        lines.join("\n")
+    }
+
+    // Everyone with at least one star for `day`, in the order they finished.
+    pub fn render_day(&self, day: &Day) -> String {
+        let day = day.raw_value();
+        let mut members: Vec<(&Member, Option<i64>, Option<i64>)> = self
+            .members
+            .values()
+            .map(|m| (m, m.star_time(day, 1), m.star_time(day, 2)))
+            .filter(|(_, part_1, _)| part_1.is_some())
+            .collect();
+        members.sort_by_key(|(m, part_1, part_2)| (part_2.unwrap_or(i64::MAX), *part_1, m.id));
+
+        let mut lines = vec![format!("Rank  {:19}  {:19}  Name", "Part 1", "Part 2")];
+        for (i, (member, part_1, part_2)) in members.iter().enumerate() {
+            let time = |ts: &Option<i64>| ts.map_or("-".to_string(), format_aoc_time);
+            lines.push(format!(
+                "{:>4}  {:19}  {:19}  {}",
+                i + 1,
+                time(part_1),
+                time(part_2),
+                member.name()
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+// The cached copy is used if it's recent enough, otherwise `fetch` is called
+// and its result saved.
+pub fn load_cached<F>(path: &Path, fetch: F) -> Result<String>
+where
+    F: FnOnce() -> Result<String>,
+{
+    let fresh = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < REFRESH_INTERVAL);
+    if fresh {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    let json = fetch()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, &json)?;
+    Ok(json)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    const JSON: &str = r#"{
+        "event": "2023",
+        "owner_id": 1,
+        "members": {
+            "1": {
+                "id": 1, "name": "alice", "stars": 3, "local_score": 10,
+                "global_score": 0, "last_star_ts": 1701410400,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701407000, "star_index": 0},
+                          "2": {"get_star_ts": 1701408000, "star_index": 1}},
+                    "2": {"1": {"get_star_ts": 1701410400, "star_index": 2}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 10,
+                "global_score": 0, "last_star_ts": 1701407500,
+                "completion_day_level": {
+                    "1": {"1": {"get_star_ts": 1701406900, "star_index": 0},
+                          "2": {"get_star_ts": 1701407500, "star_index": 1}}
+                }
+            },
+            "3": {
+                "id": 3, "name": "carol", "stars": 0, "local_score": 0,
+                "global_score": 0, "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn can_render_leaderboard() -> Result<()> {
+        let leaderboard = Leaderboard::parse(JSON)?;
+        let rendered = leaderboard.render();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "   1     10      2  *........................  2023-12-01 00:11:40  (anonymous user #2)"
+        );
+        assert_eq!(
+            lines[2],
+            "   2     10      3  *+.......................  2023-12-01 01:00:00  alice"
+        );
+        assert!(lines[3].ends_with("-                    carol"));
+        Ok(())
+    }
+
+    #[test]
+    fn can_render_a_single_day() -> Result<()> {
+        let leaderboard = Leaderboard::parse(JSON)?;
+        let rendered = leaderboard.render_day(&"2".parse().unwrap());
+        assert_eq!(
+            rendered,
+            "Rank  Part 1               Part 2               Name
+   1  2023-12-01 01:00:00  -                    alice"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn reuses_recent_downloads() -> Result<()> {
+        let path = env::temp_dir().join(format!("aoc-leaderboard-{}.json", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(load_cached(&path, || Ok("first".to_string()))?, "first");
+        assert_eq!(load_cached(&path, || Ok("second".to_string()))?, "first");
+
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(any(test, feature = "demo"))]
+pub mod examples;
+#[cfg(feature = "plugins")]
+pub mod plugins;
+pub mod runner;
+pub mod timing;
+
+// The shared code and each year live in their own crates; they're re-exported
+// here so everything can still be reached through `aoc`.
+pub use aoc_core::{cache, checkpoint, core};
+pub use aoc_utils::{
+    automaton, bitset, counter, graph, grid, grid3, lru, maths, md5, rng, search, sparse_grid,
+    string_scanner,
+};
+#[cfg(feature = "y2015")]
+pub use aoc_y2015 as y2015;
+#[cfg(feature = "y2019")]
+pub use aoc_y2019 as y2019;
+#[cfg(feature = "y2022")]
+pub use aoc_y2022 as y2022;
+#[cfg(feature = "y2023")]
+pub use aoc_y2023 as y2023;
+#[cfg(feature = "y2024")]
+pub use aoc_y2024 as y2024;
+
+use crate::core::{Answer, Day, Part, Result, Year};
+
+// Solves a puzzle straight from its input, without going through the CLI or
+// the inputs directory.
+pub fn solve(year: &Year, day: &Day, part: &Part, input: &str) -> Result<Answer> {
+    let mut solver = runner::get_solver(year, day, part)?;
+    let lines = input.lines().map(|line| Ok(line.to_string()));
+    let (answer, _) = runner::solve(solver.as_mut(), lines)?;
+    Ok(answer)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::CoreError;
+
+    #[test]
+    fn can_solve_from_a_string() -> Result<()> {
+        let year: Year = "2023".parse().unwrap();
+        let day: Day = "9".parse().unwrap();
+        let answer = solve(&year, &day, &"1".parse().unwrap(), "0 3 6 9 12 15\n")?;
+        assert_eq!(answer, "18");
+
+        let err = solve(&year, &"13".parse().unwrap(), &"1".parse().unwrap(), "").unwrap_err();
+        assert!(matches!(err, CoreError::SolverNotImplemented { .. }));
+        Ok(())
+    }
+}
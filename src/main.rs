@@ -1,20 +1,357 @@
-mod core;
-mod grid;
-mod maths;
-mod string_scanner;
-mod y2023;
+#[cfg(feature = "alloc-stats")]
+mod alloc;
+mod answers;
+mod calendar;
+mod clipboard;
+mod compare;
+mod config;
+mod history;
+mod http;
+mod interrupt;
+mod leaderboard;
+mod output;
+#[cfg(feature = "profile")]
+mod profile;
+mod puzzle;
+mod scaffold;
+mod server;
+#[cfg(feature = "tui")]
+mod ui;
+mod watch;
 
+#[cfg(feature = "demo")]
+use aoc::examples;
+use aoc::{cache, checkpoint, core, runner, timing};
 use structopt::StructOpt;
+use tracing::Level;
 
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::num::NonZeroUsize;
 use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
 
-use crate::core::{CoreError, Day, Part, Solver, Year};
+use crate::answers::Answers;
+use crate::cache::AnswerCache;
+use crate::checkpoint::Checkpoints;
+use crate::config::Config;
+use crate::core::{Answer, CoreError, Day, Part, SolutionReport, Solver, Year};
+use crate::http::AocClient;
+use crate::output::{AnswerFormat, OutputFormat, Style};
+use crate::runner::Outcome;
+use crate::timing::{PhaseTimings, Statistics};
+use crate::watch::Watcher;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "aoc", about = "Advent of Code solutions")]
 struct Opt {
+    #[structopt(
+        short,
+        long,
+        global = true,
+        parse(from_occurrences),
+        help = "Log solver events to stderr (-v info, -vv debug, -vvv trace)"
+    )]
+    verbose: u8,
+
+    #[structopt(
+        long,
+        global = true,
+        default_value = "text",
+        help = "How to print errors: text or json"
+    )]
+    error_format: OutputFormat,
+
+    #[cfg(feature = "parallel")]
+    #[structopt(
+        long,
+        global = true,
+        help = "Number of threads in the global thread pool"
+    )]
+    threads: Option<usize>,
+
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    #[structopt(about = "Run a solver against its input (the default command)")]
+    Run(RunOpt),
+    #[structopt(about = "Run every implemented day and part of a year")]
+    All(AllOpt),
+    #[cfg(feature = "tui")]
+    #[structopt(about = "Browse and run solvers in an interactive terminal UI")]
+    Tui(TuiOpt),
+    #[structopt(about = "Run the solver for today's puzzle (during December)")]
+    Today(TodayOpt),
+    #[structopt(about = "Run every implemented solver and print a results table")]
+    Report(ReportOpt),
+    #[structopt(about = "Show best and latest runtimes from the run history")]
+    Stats(StatsOpt),
+    #[structopt(about = "Show which days have solvers, inputs and recorded answers")]
+    List(ListOpt),
+    #[structopt(about = "Re-run a solver whenever its input or source changes")]
+    Watch(WatchOpt),
+    #[structopt(about = "Check every implemented solver against answers.toml")]
+    Verify(VerifyOpt),
+    #[structopt(about = "Parse every input on disk without solving, reporting any errors")]
+    CheckInputs(CheckInputsOpt),
+    #[structopt(about = "Run a solver and submit its answer to adventofcode.com")]
+    Submit(SubmitOpt),
+    #[structopt(about = "Repeatedly run a solver and report timing statistics")]
+    Bench(BenchOpt),
+    #[structopt(about = "Compare answers from two algorithms or two input files")]
+    Diff(DiffOpt),
+    #[structopt(about = "Download a day's input into the inputs directory")]
+    Fetch(FetchOpt),
+    #[structopt(about = "Show a day's puzzle text in the terminal")]
+    Read(ReadOpt),
+    #[structopt(about = "Show a private leaderboard")]
+    Leaderboard(LeaderboardOpt),
+    #[structopt(about = "Serve solvers over HTTP: POST the input to /solve/YEAR/DAY/PART")]
+    Serve(ServeOpt),
+    #[structopt(about = "Create a new day module from a template")]
+    Scaffold(ScaffoldOpt),
+    #[structopt(about = "Generate a large random input for a day")]
+    Gen(GenOpt),
+    #[cfg(feature = "demo")]
+    #[structopt(about = "Run both parts of a day against the built-in example inputs")]
+    Demo(DemoOpt),
+}
+
+#[derive(Debug, StructOpt)]
+struct RunOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt(help = "Runs both parts if omitted")]
+    part: Option<Part>,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        number_of_values = 1,
+        help = "Read input from this file instead (repeat to run several files)"
+    )]
+    input: Vec<PathBuf>,
+
+    #[structopt(
+        long,
+        conflicts_with = "input",
+        help = "Use the example input from inputs/examples"
+    )]
+    example: bool,
+
+    #[structopt(long, help = "Print parse and solve timings to stderr")]
+    time: bool,
+
+    #[structopt(long, help = "Print timings and solver statistics to stderr")]
+    stats: bool,
+
+    #[structopt(
+        long,
+        help = "Copy the answer to the clipboard (the last one if running both parts)"
+    )]
+    copy: bool,
+
+    #[structopt(
+        long,
+        default_value = "1",
+        help = "Solve this many times and print min/median/max timings to stderr"
+    )]
+    repeat: usize,
+
+    #[structopt(long, help = "Use a named alternative implementation")]
+    algo: Option<String>,
+
+    #[structopt(
+        long,
+        help = "Print the solver's parsed input to stderr before solving"
+    )]
+    dump_parsed: bool,
+
+    #[structopt(long, help = "Draw the solver's state, for solvers that can")]
+    visualize: bool,
+
+    #[structopt(
+        long,
+        help = "Carry on from the last checkpoint, for solvers that save them"
+    )]
+    resume: bool,
+
+    #[structopt(long, help = "Seed for solvers that use randomness")]
+    seed: Option<u64>,
+
+    #[cfg(feature = "alloc-stats")]
+    #[structopt(long, help = "Print heap allocation counts and peak usage to stderr")]
+    memory: bool,
+
+    #[cfg(feature = "plugins")]
+    #[structopt(
+        long,
+        parse(from_os_str),
+        number_of_values = 1,
+        help = "Load extra solvers from a plugin library (repeat to load several)"
+    )]
+    plugin: Vec<PathBuf>,
+
+    #[cfg(feature = "profile")]
+    #[structopt(
+        long,
+        help = "Write a flamegraph of the solver run to flamegraph-YYYYDD-P.svg"
+    )]
+    profile: bool,
+
+    #[structopt(flatten)]
+    answer_format: AnswerFormat,
+
+    #[structopt(
+        short,
+        long,
+        conflicts_with = "format",
+        help = "Print only the raw answer, for scripting"
+    )]
+    quiet: bool,
+
+    #[structopt(long, help = "Output format: pretty (default), text, json or markdown")]
+    format: Option<OutputFormat>,
+}
+
+#[cfg(feature = "tui")]
+#[derive(Debug, StructOpt)]
+struct TuiOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+}
+
+#[derive(Debug, StructOpt)]
+struct TodayOpt {
+    #[structopt(help = "Runs both parts if omitted")]
+    part: Option<Part>,
+}
+
+#[derive(Debug, StructOpt)]
+struct ReportOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+
+    #[structopt(flatten)]
+    answer_format: AnswerFormat,
+
+    #[structopt(
+        long,
+        default_value = "markdown",
+        help = "Output format: markdown, text or json"
+    )]
+    format: OutputFormat,
+    #[structopt(long, help = "Recompute every answer instead of using cached ones")]
+    no_cache: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct StatsOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+}
+
+#[derive(Debug, StructOpt)]
+struct ListOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+
+    #[structopt(long, parse(from_os_str), default_value = "answers.toml")]
+    answers: PathBuf,
+
+    #[structopt(short, long, help = "Show a description of each puzzle")]
+    long: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct WatchOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt()]
+    part: Part,
+
+    #[structopt(long, parse(from_os_str), help = "Read input from this file instead")]
+    input: Option<PathBuf>,
+
+    #[structopt(long, default_value = "500", help = "Polling interval in milliseconds")]
+    interval: u64,
+
+    #[structopt(long, parse(from_os_str), default_value = "crates")]
+    crates_dir: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+struct AllOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+
+    #[structopt(short, long, help = "Print only the answers, one per line")]
+    quiet: bool,
+
+    #[structopt(flatten)]
+    answer_format: AnswerFormat,
+}
+
+#[derive(Debug, StructOpt)]
+struct VerifyOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+
+    #[structopt(long, parse(from_os_str), default_value = "answers.toml")]
+    answers: PathBuf,
+
+    #[structopt(long, help = "Recompute every answer instead of using cached ones")]
+    no_cache: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct CheckInputsOpt {
+    #[structopt(help = "Defaults to the year in the config")]
+    year: Option<Year>,
+}
+
+#[derive(Debug, StructOpt)]
+struct SubmitOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt()]
+    part: Part,
+
+    #[structopt(long, parse(from_os_str), help = "Read input from this file instead")]
+    input: Option<PathBuf>,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "File containing the session cookie (default ~/.config/aoc/session)"
+    )]
+    session_file: Option<PathBuf>,
+
+    #[structopt(long, help = "Output format: pretty (default), text, json or markdown")]
+    format: Option<OutputFormat>,
+}
+
+#[derive(Debug, StructOpt)]
+struct BenchOpt {
     #[structopt()]
     year: Year,
 
@@ -23,42 +360,1073 @@ struct Opt {
 
     #[structopt()]
     part: Part,
+
+    #[structopt(long, parse(from_os_str), help = "Read input from this file instead")]
+    input: Option<PathBuf>,
+
+    #[structopt(long, default_value = "3", help = "Untimed runs before measuring")]
+    warmup: usize,
+
+    #[structopt(long, default_value = "10", help = "Number of timed runs")]
+    iterations: usize,
+
+    #[structopt(long, help = "Use a named alternative implementation")]
+    algo: Option<String>,
+
+    #[structopt(flatten)]
+    answer_format: AnswerFormat,
+
+    #[structopt(long, help = "Output format: pretty (default), text, json or markdown")]
+    format: Option<OutputFormat>,
+}
+
+#[derive(Debug, StructOpt)]
+struct DiffOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt(help = "Compares both parts if omitted")]
+    part: Option<Part>,
+
+    #[structopt(
+        long,
+        number_of_values = 1,
+        help = "Algorithm for each side (give twice, or once to compare with the default)"
+    )]
+    algo: Vec<String>,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        number_of_values = 1,
+        help = "Input for each side (give twice, or once to compare with the usual input)"
+    )]
+    input: Vec<PathBuf>,
+
+    #[structopt(long, help = "Show timings alongside the answers")]
+    timings: bool,
+}
+
+#[derive(Debug, StructOpt)]
+struct ServeOpt {
+    #[structopt(long, default_value = "127.0.0.1")]
+    host: String,
+
+    #[structopt(long, default_value = "8080")]
+    port: u16,
+}
+
+#[derive(Debug, StructOpt)]
+struct FetchOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "File containing the session cookie (default ~/.config/aoc/session)"
+    )]
+    session_file: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct ReadOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt(long, help = "Download the page again, e.g. to see part 2")]
+    refresh: bool,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "File containing the session cookie (default ~/.config/aoc/session)"
+    )]
+    session_file: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct LeaderboardOpt {
+    #[structopt(help = "The leaderboard's id (the number in its URL)")]
+    id: u64,
+
+    #[structopt(long, help = "Defaults to the year in the config")]
+    year: Option<Year>,
+
+    #[structopt(
+        long,
+        help = "Show star times for this day instead of the overall scores"
+    )]
+    day: Option<Day>,
+
+    #[structopt(
+        long,
+        parse(from_os_str),
+        help = "File containing the session cookie (default ~/.config/aoc/session)"
+    )]
+    session_file: Option<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+struct ScaffoldOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt(long, parse(from_os_str), default_value = "crates")]
+    crates_dir: PathBuf,
+}
+
+#[derive(Debug, StructOpt)]
+struct GenOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+
+    #[structopt(long, default_value = "1000")]
+    size: NonZeroUsize,
+
+    #[structopt(long, default_value = "1")]
+    seed: u64,
+}
+
+#[cfg(feature = "demo")]
+#[derive(Debug, StructOpt)]
+struct DemoOpt {
+    #[structopt()]
+    year: Year,
+
+    #[structopt()]
+    day: Day,
+}
+
+fn main() {
+    let opt = Opt::from_iter(normalise_args(env::args()));
+    if opt.command.logs_to_stderr() {
+        init_logging(opt.verbose);
+    }
+
+    if let Err(e) = run_command(&opt) {
+        eprintln!("{}", opt.error_format.render_error(&e));
+        process::exit(e.exit_code());
+    }
+}
+
+fn run_command(opt: &Opt) -> Result<(), CoreError> {
+    #[cfg(feature = "parallel")]
+    if let Some(num_threads) = opt.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build_global()
+            .map_err(|e| CoreError::General(e.to_string()))?;
+    }
+
+    let config = Config::load()?;
+
+    match &opt.command {
+        Command::Run(opt) => run(opt, &config),
+        Command::All(opt) => run_all(opt, &config),
+        #[cfg(feature = "tui")]
+        Command::Tui(tui_opt) => tui(tui_opt, opt.verbose, &config),
+        Command::Today(opt) => today(opt, &config),
+        Command::Report(opt) => report(opt, &config),
+        Command::Stats(opt) => stats(opt, &config),
+        Command::List(opt) => list(opt, &config),
+        Command::Watch(opt) => watch(opt, &config),
+        Command::Verify(opt) => verify(opt, &config),
+        Command::CheckInputs(opt) => check_inputs(opt, &config),
+        Command::Submit(opt) => submit(opt, &config),
+        Command::Bench(opt) => bench(opt, &config),
+        Command::Diff(opt) => diff(opt, &config),
+        Command::Fetch(opt) => fetch(opt, &config),
+        Command::Read(opt) => read(opt, &config),
+        Command::Leaderboard(opt) => show_leaderboard(opt, &config),
+        Command::Serve(opt) => server::serve(&opt.host, opt.port),
+        Command::Scaffold(opt) => scaffold(opt, &config),
+        Command::Gen(opt) => generate(opt),
+        #[cfg(feature = "demo")]
+        Command::Demo(opt) => demo(opt, &config),
+    }
+}
+
+impl Command {
+    // The TUI shows log output in its own panel instead.
+    fn logs_to_stderr(&self) -> bool {
+        match self {
+            #[cfg(feature = "tui")]
+            Command::Tui(_) => false,
+            _ => true,
+        }
+    }
+}
+
+// Whatever the solver can say about how far it got before being interrupted.
+fn print_partial_state(solver: &dyn Solver) {
+    for (name, value) in solver.stats() {
+        eprintln!("{}: {}", name, value);
+    }
+    if let Ok(Some(dump)) = solver.dump() {
+        eprintln!("{}", dump);
+    }
+}
+
+fn init_logging(verbose: u8) {
+    tracing_subscriber::fmt()
+        .with_max_level(log_level(verbose))
+        .with_writer(io::stderr)
+        .init();
+}
+
+fn log_level(verbose: u8) -> Level {
+    match verbose {
+        0 => Level::WARN,
+        1 => Level::INFO,
+        2 => Level::DEBUG,
+        _ => Level::TRACE,
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc::CountingAllocator = alloc::CountingAllocator;
+
+const GLOBAL_FLAGS_WITH_VALUES: &[&str] = &["--threads", "--error-format"];
+
+// `aoc 2023 7 1` is shorthand for `aoc run 2023 7 1`, and `aoc 2023 all` for
+// `aoc all 2023`. Global flags may come before the year.
+fn normalise_args(args: impl Iterator<Item = String>) -> Vec<String> {
+    let mut args: Vec<String> = args.collect();
+
+    let mut i = 1;
+    while i < args.len() && args[i].starts_with('-') {
+        i += if GLOBAL_FLAGS_WITH_VALUES.contains(&args[i].as_str()) {
+            2
+        } else {
+            1
+        };
+    }
+
+    if args.get(i).is_some_and(|arg| arg.parse::<u16>().is_ok()) {
+        if args.get(i + 1).map(String::as_str) == Some("all") {
+            args.swap(i, i + 1);
+        } else {
+            args.insert(i, "run".to_string());
+        }
+    }
+    args
+}
+
+fn run(opt: &RunOpt, config: &Config) -> Result<(), CoreError> {
+    #[cfg(feature = "plugins")]
+    for path in &opt.plugin {
+        let count = aoc::plugins::load(path)?;
+        tracing::info!(path = %path.display(), count, "loaded plugin");
+    }
+    let parts: Vec<Part> = match opt.part {
+        Some(part) => vec![part],
+        None => Part::all().collect(),
+    };
+    let format = match opt.quiet {
+        true => OutputFormat::Text,
+        false => opt.format.or(config.format).unwrap_or_default(),
+    };
+
+    // Each file is only read once, however many parts are run against it, and
+    // where the puzzle allows it only parsed once too.
+    let mut inputs: HashMap<PathBuf, Vec<String>> = HashMap::new();
+    let mut solved: HashMap<PathBuf, Box<dyn Solver>> = HashMap::new();
+    for part in &parts {
+        let paths = if opt.example {
+            vec![runner::example_path(
+                &config.inputs_dir(),
+                &opt.year,
+                &opt.day,
+                part,
+            )]
+        } else if opt.input.is_empty() {
+            vec![runner::input_path(
+                &config.inputs_dir(),
+                &opt.year,
+                &opt.day,
+            )]
+        } else {
+            opt.input.clone()
+        };
+
+        for path in &paths {
+            if !inputs.contains_key(path) {
+                let lines = runner::read_lines(path)?.collect::<io::Result<Vec<String>>>()?;
+                inputs.insert(path.clone(), lines);
+            }
+
+            let source = match paths.len() {
+                1 => None,
+                _ => Some(path.as_path()),
+            };
+            let previous = solved.remove(path);
+            let solver = run_part(opt, format, part, &inputs[path], source, previous)?;
+            solved.insert(path.clone(), solver);
+        }
+    }
+
+    Ok(())
+}
+
+fn run_part(
+    opt: &RunOpt,
+    format: OutputFormat,
+    part: &Part,
+    lines: &[String],
+    source: Option<&Path>,
+    previous: Option<Box<dyn Solver>>,
+) -> Result<Box<dyn Solver>, CoreError> {
+    let mut solver = get_algo_solver(&opt.year, &opt.day, part, &opt.algo)?;
+    let parsed = match previous {
+        Some(mut previous) => solver.take_parsed(previous.as_mut()),
+        None => false,
+    };
+    // Checkpoints are always saved, so a run can be resumed even if it wasn't
+    // expected to take long.
+    let checkpoints = match checkpoint::default_path(&opt.year, &opt.day, part, opt.algo.as_deref())
+    {
+        Ok(path) => Checkpoints::new(path, lines.join("\n").as_bytes(), opt.resume),
+        Err(_) => Checkpoints::default(),
+    };
+    solver.set_checkpoints(checkpoints.clone());
+    if let Some(seed) = opt.seed {
+        solver.set_seed(seed);
+    }
+    let lines = || lines.iter().cloned().map(Ok);
+    if !opt.quiet {
+        let label = format!(
+            "{} day {} part {}",
+            opt.year,
+            opt.day.raw_value(),
+            part.raw_value()
+        );
+        solver.set_progress(output::progress_bar(label));
+    }
+    // Parsed by a separate solver so the dump doesn't count towards timings.
+    if opt.dump_parsed {
+        let mut parsed = get_algo_solver(&opt.year, &opt.day, part, &opt.algo)?;
+        runner::check(parsed.as_mut(), lines())?;
+        match parsed.dump()? {
+            Some(dump) => eprintln!("{}", dump),
+            None => eprintln!("This solver can't dump its parsed input"),
+        }
+    }
+    #[cfg(feature = "profile")]
+    let profiler = match opt.profile {
+        true => Some(profile::Profiler::start()?),
+        false => None,
+    };
+    let cancellation = interrupt::cancellation();
+    let solve = |solver: &mut dyn Solver| match parsed {
+        true => runner::solve_parsed(solver, cancellation),
+        false => runner::solve_cancellable(solver, lines(), cancellation),
+    };
+    #[cfg(feature = "alloc-stats")]
+    let (outcome, memory) = alloc::measure(|| solve(solver.as_mut()));
+    #[cfg(not(feature = "alloc-stats"))]
+    let outcome = solve(solver.as_mut());
+    if let Err(CoreError::Interrupted(_)) = &outcome {
+        print_partial_state(solver.as_ref());
+    }
+    if opt.visualize {
+        match solver.visualize() {
+            Some(visualize) => output::show_frames(&visualize.frames()?),
+            None => eprintln!("This solver can't visualize its state"),
+        }
+    }
+    #[cfg(feature = "profile")]
+    if let Some(profiler) = profiler {
+        let path = PathBuf::from(profile::flamegraph_path(&opt.year, &opt.day, part));
+        profiler.write_flamegraph(&path)?;
+        eprintln!("Wrote flamegraph to {}", path.display());
+    }
+    let (solution, timings) = outcome?;
+    checkpoints.clear()?;
+    let mut report = SolutionReport::new(&opt.year, &opt.day, part, solution, timings.total());
+    report.title = solver.title();
+    if is_comparable(opt, parsed) {
+        record_history(std::slice::from_ref(&report), opt.algo.as_deref());
+    }
+    let rendered = format.render(&report, &opt.answer_format);
+    match format {
+        OutputFormat::Text if !opt.quiet => {
+            let mut label = vec![];
+            if opt.part.is_none() {
+                label.push(format!("Part {}", part.raw_value()));
+            }
+            if let Some(path) = source {
+                label.push(path.display().to_string());
+            }
+            match label.is_empty() {
+                true => println!("{}", rendered),
+                false => println!("{}: {}", label.join(", "), rendered),
+            }
+        }
+        OutputFormat::Pretty => match source {
+            Some(path) => {
+                let path = format!("[{}]", path.display());
+                println!("{} {}", rendered, Style::Dim.paint(&path));
+            }
+            None => println!("{}", rendered),
+        },
+        _ => println!("{}", rendered),
+    }
+    if opt.copy {
+        if let Err(e) = clipboard::copy(&report.answer.to_string()) {
+            eprintln!("Couldn't copy answer: {}", e);
+        }
+    }
+
+    if opt.time && !opt.stats {
+        eprintln!("{}", timings.summary());
+    }
+
+    // The run above may have skipped parsing, resumed from a checkpoint or
+    // been profiled, so every sample comes from a fresh solver instead.
+    if opt.repeat > 1 {
+        let mut samples = vec![];
+        for _ in 0..opt.repeat {
+            let mut solver = get_algo_solver(&opt.year, &opt.day, part, &opt.algo)?;
+            if let Some(seed) = opt.seed {
+                solver.set_seed(seed);
+            }
+            let (_, timings) = runner::solve_cancellable(solver.as_mut(), lines(), cancellation)?;
+            samples.push(timings.total());
+        }
+        if let Some(stats) = Statistics::from_samples(&samples) {
+            eprintln!("{} runs: {}", samples.len(), stats.summary());
+        }
+    }
+
+    if opt.stats {
+        eprintln!("{}", timings);
+        for (name, value) in solver.stats() {
+            eprintln!("{}: {}", name, value);
+        }
+    }
+
+    #[cfg(feature = "alloc-stats")]
+    if opt.memory {
+        eprintln!("{}", memory);
+    }
+
+    Ok(solver)
+}
+
+fn run_all(opt: &AllOpt, config: &Config) -> Result<(), CoreError> {
+    let start = Instant::now();
+    let year = config.year(opt.year)?;
+    let puzzles = runner::implemented(&year);
+
+    let mut rows = vec![];
+    let mut reports = vec![];
+    for (day, part, outcome) in runner::solve_all(&config.inputs_dir(), &year, &puzzles) {
+        let (answer, elapsed, style) = match outcome {
+            Outcome::MissingInput => ("missing input".to_string(), String::new(), Style::Dim),
+            Outcome::Solved(answer, timings) => {
+                let elapsed = timings.total();
+                reports.push(SolutionReport::new(
+                    &year,
+                    &day,
+                    &part,
+                    answer.clone(),
+                    elapsed,
+                ));
+                (
+                    opt.answer_format.apply(&answer),
+                    format!("{:?}", elapsed),
+                    Style::Answer,
+                )
+            }
+            Outcome::Cached(answer, elapsed) => (
+                opt.answer_format.apply(&answer),
+                format!("{:?} (cached)", elapsed),
+                Style::Answer,
+            ),
+            Outcome::Failed(e) => (format!("error: {}", e), String::new(), Style::Bad),
+        };
+        rows.push((day.raw_value(), part.raw_value(), answer, elapsed, style));
+    }
+
+    if opt.quiet {
+        for (_, _, answer, _, _) in rows {
+            println!("{}", answer);
+        }
+        record_history(&reports, None);
+        return Ok(());
+    }
+
+    let width = rows
+        .iter()
+        .map(|(_, _, answer, _, _)| answer.len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+    let heading = format!("Day  Part  {:width$}  Time", "Answer", width = width);
+    println!("{}", Style::Heading.paint(&heading));
+    for (day, part, answer, elapsed, style) in rows {
+        // Pad before painting so the escape codes don't throw off the widths.
+        let answer = style.paint(&format!("{:width$}", answer, width = width));
+        println!(
+            "{:>3}  {:>4}  {}  {}",
+            day,
+            part,
+            answer,
+            Style::Dim.paint(&elapsed)
+        );
+    }
+    println!("Total: {:?}", start.elapsed());
+    record_history(&reports, None);
+
+    Ok(())
+}
+
+#[cfg(feature = "tui")]
+fn tui(opt: &TuiOpt, verbose: u8, config: &Config) -> Result<(), CoreError> {
+    let log = ui::LogBuffer::default();
+    tracing_subscriber::fmt()
+        .with_max_level(log_level(verbose.max(1)))
+        .with_writer(log.clone())
+        .with_ansi(false)
+        .init();
+
+    let year = config.year(opt.year)?;
+    ui::run(year, config.inputs_dir(), log)
+}
+
+fn today(opt: &TodayOpt, config: &Config) -> Result<(), CoreError> {
+    let (year, day) =
+        calendar::puzzle_today().ok_or_else(|| CoreError::general("There's no puzzle today"))?;
+
+    let mut args = vec!["run".to_string(), year.to_string(), day.to_string()];
+    if let Some(part) = opt.part {
+        args.push(part.raw_value().to_string());
+    }
+    let run_opt = RunOpt::from_iter_safe(args).map_err(|e| CoreError::General(e.message))?;
+    run(&run_opt, config)
+}
+
+// Days without an input are left out; failures are reported as the answer so
+// they stand out in the table.
+fn report(opt: &ReportOpt, config: &Config) -> Result<(), CoreError> {
+    let year = config.year(opt.year)?;
+    let puzzles = runner::implemented(&year);
+
+    let mut reports = vec![];
+    for (day, part, outcome) in solve_all_cached(config, &year, &puzzles, !opt.no_cache)? {
+        let (answer, elapsed) = match outcome {
+            Outcome::MissingInput => continue,
+            Outcome::Solved(answer, timings) => (answer, timings.total()),
+            Outcome::Cached(answer, elapsed) => (answer, elapsed),
+            Outcome::Failed(e) => (Answer::Text(format!("error: {}", e)), Duration::ZERO),
+        };
+        let mut report = SolutionReport::new(&year, &day, &part, answer, elapsed);
+        report.title = runner::title(&year, &day);
+        reports.push(report);
+    }
+
+    println!("{}", opt.format.render_all(&reports, &opt.answer_format));
+    Ok(())
+}
+
+fn stats(opt: &StatsOpt, config: &Config) -> Result<(), CoreError> {
+    let year = config.year(opt.year)?;
+    let entries = history::load(&history::default_path()?)?;
+    let summary = history::summarise(&entries, year.raw_value());
+    if summary.is_empty() {
+        println!("No runs recorded for {}", year);
+        return Ok(());
+    }
+
+    println!(
+        "{:>3}  {:>4}  {:>4}  {:>12}  {:>12}  {:>7}  Algo",
+        "Day", "Part", "Runs", "Best", "Latest", "Trend"
+    );
+    for s in summary {
+        println!(
+            "{:>3}  {:>4}  {:>4}  {:>12}  {:>12}  {:>+6.0}%  {}",
+            s.day,
+            s.part,
+            s.runs,
+            format!("{:?}", s.best),
+            format!("{:?}", s.latest),
+            s.trend(),
+            s.algo.as_deref().unwrap_or("-")
+        );
+    }
+    Ok(())
 }
 
-fn main() -> Result<(), CoreError> {
-    let opt = Opt::from_args();
+fn list(opt: &ListOpt, config: &Config) -> Result<(), CoreError> {
+    let year = config.year(opt.year)?;
+    let answers = if opt.answers.exists() {
+        Answers::load(&opt.answers)?
+    } else {
+        Answers::default()
+    };
+    let implemented = runner::implemented(&year);
+    let mark = |present: bool| if present { "*" } else { "-" };
 
-    let filename = get_filename(&opt.year, &opt.day);
-    let lines = read_lines(&filename)?;
+    println!("Day  Part 1  Part 2  Input  Answers  Title");
+    for day in Day::all() {
+        let solvers: Vec<bool> = Part::all()
+            .map(|part| implemented.contains(&(day, part)))
+            .collect();
+        let has_input = runner::input_path(&config.inputs_dir(), &year, &day).exists();
+        let num_answers = Part::all()
+            .filter(|part| answers.get(&year, &day, part).is_some())
+            .count();
+        if !solvers.contains(&true) && !has_input && num_answers == 0 {
+            continue;
+        }
+        println!(
+            "{:>3}  {:<6}  {:<6}  {:<5}  {}/2      {}",
+            day.raw_value(),
+            mark(solvers[0]),
+            mark(solvers[1]),
+            mark(has_input),
+            num_answers,
+            runner::title(&year, &day).unwrap_or_default()
+        );
+        if let Some(description) = runner::description(&year, &day).filter(|_| opt.long) {
+            println!("     {}", description);
+        }
+    }
+
+    Ok(())
+}
+
+// Each run goes through `cargo run` so that edits to the solver are rebuilt
+// before the answer is printed again.
+fn watch(opt: &WatchOpt, config: &Config) -> Result<(), CoreError> {
+    let input = match &opt.input {
+        Some(path) => path.clone(),
+        None => runner::input_path(&config.inputs_dir(), &opt.year, &opt.day),
+    };
+    let source = scaffold::day_source(&opt.crates_dir, &opt.year, &opt.day);
+    let mut watcher = Watcher::new(vec![input.clone(), source]);
+
+    let mut args: Vec<String> = ["run", "--quiet", "--", "run"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    args.extend([
+        opt.year.raw_value().to_string(),
+        opt.day.raw_value().to_string(),
+        opt.part.raw_value().to_string(),
+        "--time".to_string(),
+        "--input".to_string(),
+        input.display().to_string(),
+    ]);
+
+    let paths: Vec<String> = watcher
+        .paths()
+        .iter()
+        .map(|p| p.display().to_string())
+        .collect();
+    eprintln!("Watching {}", paths.join(", "));
+    loop {
+        if watcher.changed() {
+            println!("---");
+            if let Err(e) = process::Command::new("cargo").args(&args).status() {
+                eprintln!("Couldn't run cargo: {}", e);
+            }
+        }
+        thread::sleep(Duration::from_millis(opt.interval));
+    }
+}
+
+fn verify(opt: &VerifyOpt, config: &Config) -> Result<(), CoreError> {
+    let answers = Answers::load(&opt.answers)?;
+    let year = config.year(opt.year)?;
+
+    let puzzles: Vec<(Day, Part)> = runner::implemented(&year)
+        .into_iter()
+        .filter(|(day, part)| answers.get(&year, day, part).is_some())
+        .collect();
+
+    let mut failures = 0;
+    for (day, part, outcome) in solve_all_cached(config, &year, &puzzles, !opt.no_cache)? {
+        let expected = answers.get(&year, &day, &part).unwrap_or_default();
+        let status = match outcome {
+            Outcome::MissingInput => Style::Dim.paint("missing input"),
+            Outcome::Cached(answer, _) if answer.matches(&expected) => {
+                Style::Good.paint("ok (cached)")
+            }
+            Outcome::Cached(answer, _) => {
+                failures += 1;
+                let msg = format!("FAIL (expected {}, got {})", expected, answer);
+                Style::Bad.paint(&msg)
+            }
+            Outcome::Solved(answer, _) if answer.matches(&expected) => Style::Good.paint("ok"),
+            Outcome::Solved(answer, _) => {
+                failures += 1;
+                let msg = format!("FAIL (expected {}, got {})", expected, answer);
+                Style::Bad.paint(&msg)
+            }
+            Outcome::Failed(e) => {
+                failures += 1;
+                Style::Bad.paint(&format!("FAIL ({})", e))
+            }
+        };
+        println!(
+            "{:>3}  {:>4}  {}",
+            day.raw_value(),
+            part.raw_value(),
+            status
+        );
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(CoreError::General(format!(
+            "{} answers failed verification",
+            n
+        ))),
+    }
+}
+
+fn check_inputs(opt: &CheckInputsOpt, config: &Config) -> Result<(), CoreError> {
+    let year = config.year(opt.year)?;
+    let inputs_dir = config.inputs_dir();
+
+    let mut failures = 0;
+    for (day, part) in runner::implemented(&year) {
+        let status = match runner::check_day(&inputs_dir, &year, &day, &part) {
+            Ok(lines) => Style::Good.paint(&format!("ok ({} lines)", lines)),
+            Err(CoreError::MissingInput(_)) => Style::Dim.paint("missing input"),
+            Err(e) => {
+                failures += 1;
+                Style::Bad.paint(&format!("FAIL ({})", e))
+            }
+        };
+        println!(
+            "{:>3}  {:>4}  {}",
+            day.raw_value(),
+            part.raw_value(),
+            status
+        );
+    }
+
+    match failures {
+        0 => Ok(()),
+        n => Err(CoreError::General(format!("{} inputs failed to parse", n))),
+    }
+}
+
+fn submit(opt: &SubmitOpt, config: &Config) -> Result<(), CoreError> {
+    let client = aoc_client(&opt.session_file, config)?;
+
+    let mut solver = runner::get_solver(&opt.year, &opt.day, &opt.part)?;
+    let (solution, timings) =
+        solve_input(solver.as_mut(), config, &opt.year, &opt.day, &opt.input)?;
+    let mut report = SolutionReport::new(&opt.year, &opt.day, &opt.part, solution, timings.total());
+    report.title = solver.title();
+    // The answer is shown as it'll be sent, so without any --group-digits.
+    let format = opt.format.or(config.format).unwrap_or_default();
+    println!("{}", format.render(&report, &AnswerFormat::default()));
+
+    let status =
+        client.submit_answer(&opt.year, &opt.day, &opt.part, &report.answer.to_string())?;
+    println!("{}", status);
+
+    Ok(())
+}
+
+fn bench(opt: &BenchOpt, config: &Config) -> Result<(), CoreError> {
+    let filename = match &opt.input {
+        Some(path) => path.clone(),
+        None => runner::input_path(&config.inputs_dir(), &opt.year, &opt.day),
+    };
+    let lines = runner::read_lines(&filename)?.collect::<io::Result<Vec<String>>>()?;
+
+    let mut samples = vec![];
+    let mut solution = Answer::Text(String::new());
+    let mut title = None;
+    for i in 0..(opt.warmup + opt.iterations) {
+        let mut solver = get_algo_solver(&opt.year, &opt.day, &opt.part, &opt.algo)?;
+        let start = Instant::now();
+        let (answer, _) = runner::solve(solver.as_mut(), lines.iter().cloned().map(Ok))?;
+        if i >= opt.warmup {
+            samples.push(start.elapsed());
+        }
+        solution = answer;
+        title = solver.title();
+    }
+
+    // The report carries the median, and the full breakdown goes to stderr so
+    // that --format json stays parseable.
+    let stats = Statistics::from_samples(&samples);
+    let elapsed = stats.map(|stats| stats.median).unwrap_or_default();
+    let mut report = SolutionReport::new(&opt.year, &opt.day, &opt.part, solution, elapsed);
+    report.title = title;
+    let format = opt.format.or(config.format).unwrap_or_default();
+    println!("{}", format.render(&report, &opt.answer_format));
+    match stats {
+        Some(stats) => eprintln!("{} iterations\n{}", samples.len(), stats),
+        None => eprintln!("No timed iterations"),
+    }
+
+    Ok(())
+}
+
+fn diff(opt: &DiffOpt, config: &Config) -> Result<(), CoreError> {
+    if opt.algo.len() > 2 || opt.input.len() > 2 {
+        return Err(CoreError::general("Give --algo and --input at most twice"));
+    }
+    if opt.algo.is_empty() && opt.input.is_empty() {
+        return Err(CoreError::general(
+            "Nothing to compare: give --algo or --input",
+        ));
+    }
+    let parts: Vec<Part> = match opt.part {
+        Some(part) => vec![part],
+        None => Part::all().collect(),
+    };
+
+    let mut labels = vec![];
+    let mut sides = vec![];
+    for side in 0..2 {
+        let algo = diff_side(&opt.algo, side, opt.input.len() == 2);
+        let input = diff_side(&opt.input, side, !opt.algo.is_empty());
+        let mut label = vec![];
+        if let Some(algo) = algo {
+            label.push(format!("algo {}", algo));
+        }
+        if let Some(input) = input {
+            label.push(input.display().to_string());
+        }
+        labels.push(match label.is_empty() {
+            true => "default".to_string(),
+            false => label.join(", "),
+        });
+
+        let mut reports = vec![];
+        for part in &parts {
+            let mut solver = get_algo_solver(&opt.year, &opt.day, part, &algo.cloned())?;
+            let input = input.cloned();
+            let (answer, timings) =
+                solve_input(solver.as_mut(), config, &opt.year, &opt.day, &input)?;
+            let mut report =
+                SolutionReport::new(&opt.year, &opt.day, part, answer, timings.total());
+            report.title = solver.title();
+            reports.push(report);
+        }
+        sides.push(reports);
+    }
+
+    let comparison = compare::compare((&labels[0], &labels[1]), &sides[0], &sides[1], opt.timings);
+    println!("{}", output::render_comparison(&comparison.lines));
 
-    let mut solver = get_solver(&opt.year, &opt.day, &opt.part);
+    match comparison.differences {
+        0 => Ok(()),
+        n => Err(CoreError::General(format!("{} answers differ", n))),
+    }
+}
+
+// A value given once is shared by both sides if the other option is what
+// differs between them; otherwise it's compared against the default.
+fn diff_side<T>(values: &[T], side: usize, shared: bool) -> Option<&T> {
+    match values.len() {
+        1 if shared || side == 1 => values.first(),
+        2 => values.get(side),
+        _ => None,
+    }
+}
+
+fn fetch(opt: &FetchOpt, config: &Config) -> Result<(), CoreError> {
+    let path = runner::input_path(&config.inputs_dir(), &opt.year, &opt.day);
+    if path.exists() {
+        let msg = format!("{} already exists, not downloading again", path.display());
+        return Err(CoreError::general(&msg));
+    }
+
+    let client = aoc_client(&opt.session_file, config)?;
+    let input = client.get_input(&opt.year, &opt.day)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+    println!("Saved {}", path.display());
+
+    Ok(())
+}
+
+fn read(opt: &ReadOpt, config: &Config) -> Result<(), CoreError> {
+    let path = cache::cache_dir()?
+        .join("puzzles")
+        .join(format!("{}{}.html", opt.year, opt.day));
+    let html = match fs::read_to_string(&path) {
+        Ok(html) if !opt.refresh => html,
+        _ => {
+            let html = aoc_client(&opt.session_file, config)?.get_puzzle(&opt.year, &opt.day)?;
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&path, &html)?;
+            html
+        }
+    };
 
-    for line in lines {
-        solver.handle_line(&line?)?;
+    let articles = puzzle::articles(&html);
+    if articles.is_empty() {
+        return Err(CoreError::general("Couldn't find the puzzle on the page"));
     }
+    for article in articles {
+        for line in puzzle::to_markdown(article).lines() {
+            match line.starts_with("## ") {
+                true => println!("{}", Style::Heading.paint(line)),
+                false => println!("{}", line),
+            }
+        }
+        println!();
+    }
+    Ok(())
+}
 
-    let solution = solver.extract_solution()?;
-    println!("{}", solution);
+fn show_leaderboard(opt: &LeaderboardOpt, config: &Config) -> Result<(), CoreError> {
+    let year = config.year(opt.year)?;
+    let path = cache::cache_dir()?.join(format!("leaderboard-{}-{}.json", year, opt.id));
+    let json = leaderboard::load_cached(&path, || {
+        aoc_client(&opt.session_file, config)?.get_leaderboard(&year, opt.id)
+    })?;
 
+    let leaderboard = leaderboard::Leaderboard::parse(&json)?;
+    match &opt.day {
+        Some(day) => println!("{}", leaderboard.render_day(day)),
+        None => println!("{}", leaderboard.render()),
+    }
     Ok(())
 }
 
-fn get_filename(year: &Year, day: &Day) -> PathBuf {
-    let short_filename = format!("{}{}.txt", year.to_string(), day.to_string(),);
-    PathBuf::from(".").join("inputs").join(short_filename)
+fn scaffold(opt: &ScaffoldOpt, config: &Config) -> Result<(), CoreError> {
+    let path = scaffold::scaffold(&opt.crates_dir, &config.inputs_dir(), &opt.year, &opt.day)?;
+    println!("Created {}", path.display());
+    Ok(())
 }
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+fn generate(opt: &GenOpt) -> Result<(), CoreError> {
+    for line in runner::generate(&opt.year, &opt.day, opt.size.get(), opt.seed)? {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "demo")]
+fn demo(opt: &DemoOpt, config: &Config) -> Result<(), CoreError> {
+    for part in Part::all() {
+        // An example in the inputs directory wins over the built-in one.
+        let path = runner::example_path(&config.inputs_dir(), &opt.year, &opt.day, &part);
+        let example = fs::read_to_string(path).ok().or_else(|| {
+            examples::example(opt.year.raw_value(), opt.day.raw_value(), part.raw_value())
+                .map(String::from)
+        });
+        let text = match example {
+            Some(text) => text,
+            None => {
+                let msg = format!("No example for {} day {} part {}", opt.year, opt.day, part);
+                return Err(CoreError::general(&msg));
+            }
+        };
+
+        let mut solver = runner::get_solver(&opt.year, &opt.day, &part)?;
+        let lines = text.lines().map(|line| Ok(line.to_string()));
+        let (solution, _) = runner::solve(solver.as_mut(), lines)?;
+        println!("Part {}: {}", part.raw_value(), solution);
+    }
+    Ok(())
+}
+
+fn solve_input(
+    solver: &mut dyn Solver,
+    config: &Config,
+    year: &Year,
+    day: &Day,
+    input: &Option<PathBuf>,
+) -> Result<(Answer, PhaseTimings), CoreError> {
+    let filename = match input {
+        Some(path) => path.clone(),
+        None => runner::input_path(&config.inputs_dir(), year, day),
+    };
+    let lines = runner::read_lines(&filename)?;
+    runner::solve(solver, lines)
+}
+
+// Only full runs of built-in solvers against the real input are comparable
+// over time: reusing a parse, resuming or profiling all skew the timing.
+fn is_comparable(opt: &RunOpt, parsed: bool) -> bool {
+    #[cfg(feature = "plugins")]
+    if !opt.plugin.is_empty() {
+        return false;
+    }
+    #[cfg(feature = "profile")]
+    if opt.profile {
+        return false;
+    }
+    !parsed && !opt.resume && !opt.example && opt.input.is_empty()
+}
+
+fn record_history(reports: &[SolutionReport], algo: Option<&str>) {
+    if reports.is_empty() {
+        return;
+    }
+    let recorded = history::default_path().and_then(|path| history::record(&path, reports, algo));
+    if let Err(e) = recorded {
+        eprintln!("Couldn't record run history: {}", e);
+    }
+}
+
+fn solve_all_cached(
+    config: &Config,
+    year: &Year,
+    puzzles: &[(Day, Part)],
+    use_cache: bool,
+) -> Result<Vec<(Day, Part, Outcome)>, CoreError> {
+    if !use_cache {
+        return Ok(runner::solve_all(&config.inputs_dir(), year, puzzles));
+    }
+
+    let mut cache = AnswerCache::load(&cache::default_path()?)?;
+    let outcomes = runner::solve_all_cached(&config.inputs_dir(), year, puzzles, &mut cache);
+    cache.save()?;
+    Ok(outcomes)
+}
+
+// An explicit --session-file wins over AOC_SESSION, which wins over the
+// configured (or default) session file.
+fn aoc_client(session_file: &Option<PathBuf>, config: &Config) -> Result<AocClient, CoreError> {
+    match (session_file, &config.session) {
+        (None, Some(session)) => Ok(AocClient::new(session)),
+        _ => AocClient::from_session_file(session_file.clone().or(config.session_file.clone())),
+    }
 }
 
-fn get_solver(year: &Year, day: &Day, part: &Part) -> Box<dyn Solver> {
-    match year.raw_value() {
-        2023 => y2023::get_solver(day, part),
-        _ => todo!(),
+fn get_algo_solver(
+    year: &Year,
+    day: &Day,
+    part: &Part,
+    algo: &Option<String>,
+) -> Result<Box<dyn Solver>, CoreError> {
+    match algo {
+        Some(name) => runner::get_variant(year, day, part, name),
+        None => runner::get_solver(year, day, part),
     }
 }
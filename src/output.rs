@@ -0,0 +1,403 @@
+use std::env;
+use std::io::{self, IsTerminal};
+use std::str::FromStr;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+use structopt::StructOpt;
+
+use crate::core::{Answer, CoreError, Progress, SolutionReport};
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Pretty,
+    Text,
+    Json,
+    Markdown,
+}
+
+impl FromStr for OutputFormat {
+    type Err = CoreError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Self::Pretty),
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "markdown" | "md" => Ok(Self::Markdown),
+            _ => Err(CoreError::general(&format!("Unknown output format: {}", s))),
+        }
+    }
+}
+
+impl OutputFormat {
+    pub fn render(&self, report: &SolutionReport, answer_format: &AnswerFormat) -> String {
+        match self {
+            Self::Pretty => pretty(report, answer_format, colors_enabled()),
+            Self::Text => answer_format.apply(&report.answer),
+            Self::Json => serde_json::to_string(report).unwrap_or_default(),
+            Self::Markdown => self.render_all(std::slice::from_ref(report), answer_format),
+        }
+    }
+
+    pub fn render_all(&self, reports: &[SolutionReport], answer_format: &AnswerFormat) -> String {
+        match self {
+            Self::Pretty => reports
+                .iter()
+                .map(|r| pretty(r, answer_format, colors_enabled()))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Text => reports
+                .iter()
+                .map(|r| {
+                    format!(
+                        "{} day {} part {}: {} ({}ms)",
+                        r.year,
+                        r.day,
+                        r.part,
+                        answer_format.apply(&r.answer),
+                        r.elapsed.as_millis()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Self::Json => serde_json::to_string(reports).unwrap_or_default(),
+            Self::Markdown => {
+                // Titles get their own column, but only if there are any.
+                let titles = reports.iter().any(|r| r.title.is_some());
+                let (heading, rule) = match titles {
+                    true => ("| Puzzle ", "|:-------"),
+                    false => ("", ""),
+                };
+                let mut lines = vec![
+                    format!("| Year | Day {}| Part | Answer | Time |", heading),
+                    format!("|-----:|----:{}|-----:|-------:|-----:|", rule),
+                ];
+                for r in reports {
+                    let title = match titles {
+                        true => format!("| {} ", r.title.unwrap_or_default()),
+                        false => String::new(),
+                    };
+                    lines.push(format!(
+                        "| {} | {} {}| {} | `{}` | {:.3}ms |",
+                        r.year,
+                        r.day,
+                        title,
+                        r.part,
+                        answer_format.apply(&r.answer),
+                        r.elapsed.as_secs_f64() * 1000.0
+                    ));
+                }
+                lines.join("\n")
+            }
+        }
+    }
+
+    pub fn render_error(&self, error: &CoreError) -> String {
+        match self {
+            Self::Pretty | Self::Text | Self::Markdown => format!("Error: {}", error),
+            Self::Json => {
+                let mut value = serde_json::json!({
+                    "error": error.kind(),
+                    "message": error.to_string(),
+                    "exit_code": error.exit_code(),
+                });
+                if let CoreError::Parse { line, text, .. } = error {
+                    value["line"] = (*line).into();
+                    value["text"] = text.as_str().into();
+                }
+                if let Some(column) = error.column() {
+                    value["column"] = column.into();
+                }
+                value.to_string()
+            }
+        }
+    }
+}
+
+fn pretty(report: &SolutionReport, answer_format: &AnswerFormat, color: bool) -> String {
+    let title = match report.title {
+        Some(title) => format!(" {}", Style::Dim.paint_if(&format!("({})", title), color)),
+        None => String::new(),
+    };
+    format!(
+        "{}{} {}: {} {}",
+        Style::Heading.paint_if(&format!("{} day {}", report.year, report.day), color),
+        title,
+        Style::Heading.paint_if(&format!("part {}", report.part), color),
+        Style::Answer.paint_if(&answer_format.apply(&report.answer), color),
+        Style::Dim.paint_if(
+            &format!("({:.3}ms)", report.elapsed.as_secs_f64() * 1000.0),
+            color
+        )
+    )
+}
+
+// For `diff`: removed lines in red and added ones in green, like a unified
+// diff.
+pub fn render_comparison(lines: &[String]) -> String {
+    lines
+        .iter()
+        .map(|line| match line.chars().next() {
+            Some('-') => Style::Bad.paint(line),
+            Some('+') => Style::Good.paint(line),
+            _ => line.clone(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Colours are only used when stdout is a terminal, and never if NO_COLOR is
+// set (https://no-color.org).
+pub fn colors_enabled() -> bool {
+    static ENABLED: OnceLock<bool> = OnceLock::new();
+    *ENABLED.get_or_init(|| env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal())
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Style {
+    Heading,
+    Answer,
+    Dim,
+    Good,
+    Bad,
+}
+
+impl Style {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Heading => "1",
+            Self::Answer => "1;32",
+            Self::Dim => "2",
+            Self::Good => "32",
+            Self::Bad => "31",
+        }
+    }
+
+    pub fn paint(&self, text: &str) -> String {
+        self.paint_if(text, colors_enabled())
+    }
+
+    fn paint_if(&self, text: &str, color: bool) -> String {
+        match color {
+            true => format!("\x1b[{}m{}\x1b[0m", self.code(), text),
+            false => text.to_string(),
+        }
+    }
+}
+
+// Redraws a single line on stderr, or logs each update when stderr isn't a
+// terminal.
+pub fn progress_bar(label: String) -> Progress {
+    let terminal = io::stderr().is_terminal();
+    Progress::new(move |done, total| {
+        let percent = match total {
+            0 => 100.0,
+            _ => done as f64 * 100.0 / total as f64,
+        };
+        if terminal {
+            eprint!("\r{}: {} of {} ({:.0}%)", label, done, total, percent);
+            if done >= total {
+                eprintln!();
+            }
+        } else {
+            tracing::info!(done, total, "{}", label);
+        }
+    })
+}
+
+const FRAME_DELAY: Duration = Duration::from_millis(500);
+
+// Animates the frames in place on a terminal; otherwise they're printed one
+// after another.
+pub fn show_frames(frames: &[String]) {
+    let animate = frames.len() > 1 && io::stdout().is_terminal();
+    for (i, frame) in frames.iter().enumerate() {
+        if animate {
+            if i > 0 {
+                thread::sleep(FRAME_DELAY);
+            }
+            print!("\x1b[2J\x1b[H");
+        } else if i > 0 {
+            println!();
+        }
+        println!("{}", frame.trim_end());
+    }
+}
+
+#[derive(Debug, Default, Clone, StructOpt)]
+pub struct AnswerFormat {
+    #[structopt(long, help = "Separate thousands in numeric answers")]
+    pub group_digits: bool,
+
+    #[structopt(long, help = "Print numeric answers in hexadecimal")]
+    pub hex: bool,
+}
+
+impl AnswerFormat {
+    // Text answers are passed through untouched.
+    pub fn apply(&self, answer: &Answer) -> String {
+        let value = match answer {
+            Answer::Unsigned(n) => *n as i128,
+            Answer::Signed(n) => *n as i128,
+            Answer::Text(text) => return text.clone(),
+        };
+
+        let sign = if value < 0 { "-" } else { "" };
+        let magnitude = value.unsigned_abs();
+
+        match (self.hex, self.group_digits) {
+            (true, true) => format!("{}0x{}", sign, group(&format!("{:x}", magnitude), 4, '_')),
+            (true, false) => format!("{}0x{:x}", sign, magnitude),
+            (false, true) => format!("{}{}", sign, group(&magnitude.to_string(), 3, ',')),
+            (false, false) => value.to_string(),
+        }
+    }
+}
+
+fn group(digits: &str, size: usize, separator: char) -> String {
+    let mut result = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(size) {
+            result.push(separator);
+        }
+        result.push(c);
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn format(group_digits: bool, hex: bool) -> AnswerFormat {
+        AnswerFormat { group_digits, hex }
+    }
+
+    #[test]
+    fn can_group_digits() {
+        let f = format(true, false);
+        assert_eq!(f.apply(&1235403232800u64.into()), "1,235,403,232,800");
+        assert_eq!(f.apply(&(-1234i64).into()), "-1,234");
+        assert_eq!(f.apply(&999u64.into()), "999");
+        assert_eq!(f.apply(&"No value".into()), "No value");
+    }
+
+    #[test]
+    fn can_render_report_as_json() {
+        let report = SolutionReport {
+            year: 2023,
+            day: 7,
+            part: 1,
+            title: None,
+            answer: 6440u64.into(),
+            elapsed: std::time::Duration::from_micros(12_345),
+        };
+        assert_eq!(
+            OutputFormat::Json.render(&report, &format(true, false)),
+            r#"{"year":2023,"day":7,"part":1,"answer":"6440","elapsed_ms":12}"#
+        );
+        assert_eq!(
+            OutputFormat::Text.render(&report, &format(true, false)),
+            "6,440"
+        );
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn can_render_reports_as_markdown() {
+        let reports = [
+            SolutionReport {
+                year: 2023,
+                day: 7,
+                part: 1,
+                title: None,
+                answer: 6440u64.into(),
+                elapsed: std::time::Duration::from_micros(1_500),
+            },
+            SolutionReport {
+                year: 2023,
+                day: 7,
+                part: 2,
+                title: None,
+                answer: 5905u64.into(),
+                elapsed: std::time::Duration::from_micros(250),
+            },
+        ];
+        assert_eq!(
+            OutputFormat::Markdown.render_all(&reports, &format(false, false)),
+            "| Year | Day | Part | Answer | Time |
+|-----:|----:|-----:|-------:|-----:|
+| 2023 | 7 | 1 | `6440` | 1.500ms |
+| 2023 | 7 | 2 | `5905` | 0.250ms |"
+        );
+
+        let mut titled = reports[0].clone();
+        titled.title = Some("Camel Cards");
+        assert_eq!(
+            OutputFormat::Markdown.render_all(&[titled], &format(false, false)),
+            "| Year | Day | Puzzle | Part | Answer | Time |
+|-----:|----:|:-------|-----:|-------:|-----:|
+| 2023 | 7 | Camel Cards | 1 | `6440` | 1.500ms |"
+        );
+        assert_eq!(
+            "md".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Markdown
+        );
+    }
+
+    #[test]
+    fn can_render_errors_as_json() {
+        let error = CoreError::parse(4, "", CoreError::general("bad"));
+        assert_eq!(
+            OutputFormat::Json.render_error(&error),
+            r#"{"error":"parse","exit_code":3,"line":4,"message":"Line 4: General Error: bad","text":""}"#
+        );
+        assert_eq!(
+            OutputFormat::Text.render_error(&error),
+            "Error: Line 4: General Error: bad"
+        );
+    }
+
+    #[test]
+    fn can_render_pretty_reports() {
+        let report = SolutionReport {
+            year: 2023,
+            day: 7,
+            part: 2,
+            title: None,
+            answer: 5905u64.into(),
+            elapsed: std::time::Duration::from_micros(1_500),
+        };
+        assert_eq!(
+            pretty(&report, &format(false, false), false),
+            "2023 day 7 part 2: 5905 (1.500ms)"
+        );
+        assert_eq!(
+            pretty(&report, &format(true, false), true),
+            "\x1b[1m2023 day 7\x1b[0m \x1b[1mpart 2\x1b[0m: \x1b[1;32m5,905\x1b[0m \x1b[2m(1.500ms)\x1b[0m"
+        );
+
+        let mut titled = report.clone();
+        titled.title = Some("Camel Cards");
+        assert_eq!(
+            pretty(&titled, &format(false, false), false),
+            "2023 day 7 (Camel Cards) part 2: 5905 (1.500ms)"
+        );
+        assert_eq!(OutputFormat::default(), OutputFormat::Pretty);
+    }
+
+    #[test]
+    fn can_format_as_hex() {
+        assert_eq!(format(false, true).apply(&255u64.into()), "0xff");
+        assert_eq!(
+            format(true, true).apply(&1235403232800u64.into()),
+            "0x11f_a3c5_5620"
+        );
+        assert_eq!(format(false, false).apply(&6440u64.into()), "6440");
+    }
+}
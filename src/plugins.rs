@@ -0,0 +1,93 @@
+use std::{path::Path, sync::RwLock};
+
+use libloading::{Library, Symbol};
+
+use aoc_core::plugin::{self, Solvers};
+
+use crate::core::{CoreError, Day, Part, Result, SolverFn, Year};
+
+static LOADED: RwLock<Vec<(u16, u16, u16, SolverFn)>> = RwLock::new(Vec::new());
+
+// Loads a plugin's solvers, returning how many it provides. Solvers from a
+// plugin take precedence over the built-in ones, and over those from any
+// plugin loaded before it.
+pub fn load(path: &Path) -> Result<usize> {
+    let fail = |e: libloading::Error| {
+        CoreError::General(format!("Couldn't load plugin {}: {}", path.display(), e))
+    };
+    // SAFETY: there's no checking that the plugin was built against this
+    // version of aoc-core; see `aoc_core::plugin`.
+    let solvers = unsafe {
+        let library = Library::new(path).map_err(fail)?;
+        let exported: Symbol<fn() -> Solvers> = library.get(plugin::SYMBOL).map_err(fail)?;
+        let solvers = exported();
+        // The solvers point into the library, so it has to stay loaded.
+        std::mem::forget(library);
+        solvers
+    };
+    register(solvers);
+    Ok(solvers.len())
+}
+
+fn register(solvers: Solvers) {
+    let mut loaded = LOADED.write().unwrap();
+    loaded.splice(0..0, solvers.iter().copied());
+}
+
+pub fn find(year: &Year, day: &Day, part: &Part) -> Option<SolverFn> {
+    let loaded = LOADED.read().unwrap();
+    loaded
+        .iter()
+        .find(|(y, d, p, _)| {
+            *y == year.raw_value() && *d == day.raw_value() && *p == part.raw_value()
+        })
+        .map(|(_, _, _, solver)| *solver)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::{Answer, Solver};
+
+    #[derive(Debug)]
+    struct Fixed(u64);
+
+    impl Solver for Fixed {
+        fn handle_line(&mut self, _line: &str) -> Result<()> {
+            Ok(())
+        }
+
+        fn extract_solution(&mut self) -> Result<Answer> {
+            Ok(self.0.into())
+        }
+    }
+
+    fn one() -> Box<dyn Solver> {
+        Box::new(Fixed(1))
+    }
+
+    fn two() -> Box<dyn Solver> {
+        Box::new(Fixed(2))
+    }
+
+    #[test]
+    fn later_plugins_take_precedence() -> Result<()> {
+        let year = Year::new(2015)?;
+        let part: Part = "1".parse().unwrap();
+        assert!(find(&year, &"4".parse().unwrap(), &part).is_none());
+
+        register(&[(2015, 4, 1, one), (2015, 5, 1, one)]);
+        register(&[(2015, 4, 1, two)]);
+        let mut solver = find(&year, &"4".parse().unwrap(), &part).unwrap()();
+        assert_eq!(solver.extract_solution()?, "2");
+        let mut solver = find(&year, &"5".parse().unwrap(), &part).unwrap()();
+        assert_eq!(solver.extract_solution()?, "1");
+        Ok(())
+    }
+
+    #[test]
+    fn missing_plugins_are_reported() {
+        let err = load(Path::new("/nonexistent/libplugin.so")).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/libplugin.so"));
+    }
+}
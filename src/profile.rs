@@ -0,0 +1,50 @@
+use std::{fs::File, path::Path};
+
+use pprof::{ProfilerGuard, ProfilerGuardBuilder};
+
+use crate::core::{CoreError, Day, Part, Result, Year};
+
+// Samples the whole process from `start` until the flamegraph is written.
+pub struct Profiler(ProfilerGuard<'static>);
+
+impl Profiler {
+    pub fn start() -> Result<Self> {
+        let guard = ProfilerGuardBuilder::default()
+            .frequency(1000)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map_err(|e| CoreError::General(format!("Couldn't start profiler: {}", e)))?;
+        Ok(Self(guard))
+    }
+
+    pub fn write_flamegraph(self, path: &Path) -> Result<()> {
+        let report = self
+            .0
+            .report()
+            .build()
+            .map_err(|e| CoreError::General(format!("Couldn't build profile: {}", e)))?;
+        let file = File::create(path)?;
+        report
+            .flamegraph(file)
+            .map_err(|e| CoreError::General(format!("Couldn't write flamegraph: {}", e)))
+    }
+}
+
+pub fn flamegraph_path(year: &Year, day: &Day, part: &Part) -> String {
+    format!("flamegraph-{}{}-{}.svg", year, day, part.raw_value())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names_flamegraphs_by_puzzle() {
+        let path = flamegraph_path(
+            &"2023".parse().unwrap(),
+            &"10".parse().unwrap(),
+            &"2".parse().unwrap(),
+        );
+        assert_eq!(path, "flamegraph-202310-2.svg");
+    }
+}
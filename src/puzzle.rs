@@ -0,0 +1,160 @@
+// Puzzle pages are simple enough that a handful of tags covers them: each
+// part is an `<article class="day-desc">` of headings, paragraphs, lists and
+// preformatted examples.
+
+// The inner HTML of each `<article>`; there's a second one once part 1 has
+// been solved.
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let article = &rest[start..];
+        let (Some(open_end), Some(end)) = (article.find('>'), article.find("</article>")) else {
+            break;
+        };
+        articles.push(&article[open_end + 1..end]);
+        rest = &article[end + "</article>".len()..];
+    }
+    articles
+}
+
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                // A tag that's never closed is just text.
+                let Some(end) = rest.find('>') else {
+                    out.push_str(&decode_entities(rest));
+                    break;
+                };
+                let tag = &rest[1..end];
+                let closing = tag.starts_with('/');
+                let name = tag
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default();
+                match (name, closing) {
+                    ("h2", false) => out.push_str("\n## "),
+                    ("h2", true) | ("p", true) | ("ul", true) => out.push_str("\n\n"),
+                    ("pre", false) => {
+                        out.push_str("\n```\n");
+                        in_pre = true;
+                    }
+                    ("pre", true) => {
+                        if !out.ends_with('\n') {
+                            out.push('\n');
+                        }
+                        out.push_str("```\n\n");
+                        in_pre = false;
+                    }
+                    ("code", _) if !in_pre => out.push('`'),
+                    ("em", _) => out.push('*'),
+                    ("li", false) => out.push_str("- "),
+                    ("li", true) => out.push('\n'),
+                    _ => {}
+                }
+                rest = &rest[end + 1..];
+            }
+            Some(i) => {
+                // Line breaks between block tags aren't part of the text.
+                let text = &rest[..i];
+                if in_pre || !(text.trim().is_empty() && text.contains('\n')) {
+                    out.push_str(&decode_entities(text));
+                }
+                rest = &rest[i..];
+            }
+            None => {
+                out.push_str(&decode_entities(rest));
+                break;
+            }
+        }
+    }
+
+    collapse_blank_lines(out.trim())
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn collapse_blank_lines(text: &str) -> String {
+    let mut out = String::new();
+    let mut blank_lines = 0;
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            blank_lines += 1;
+            if blank_lines > 1 {
+                continue;
+            }
+        } else {
+            blank_lines = 0;
+        }
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 13: Point of Incidence ---</h2><p>You find a <em>mirror</em> at <code>#.##</code> &amp; more.</p>
+<pre><code>#.##..##.
+..#.##.#.
+</code></pre>
+<ul>
+<li>First &lt;one&gt;</li>
+<li>Second</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>405</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now fix the smudge.</p></article>
+</main></body></html>"#;
+
+    #[test]
+    fn can_find_articles() {
+        let articles = articles(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert!(articles[1].starts_with("<h2 id=\"part2\">"));
+    }
+
+    #[test]
+    fn can_convert_to_markdown() {
+        let markdown: Vec<String> = articles(PAGE).into_iter().map(to_markdown).collect();
+        assert_eq!(
+            markdown[0],
+            "## --- Day 13: Point of Incidence ---
+
+You find a *mirror* at `#.##` & more.
+
+```
+#.##..##.
+..#.##.#.
+```
+
+- First <one>
+- Second
+"
+        );
+        assert_eq!(markdown[1], "## --- Part Two ---\n\nNow fix the smudge.\n");
+    }
+
+    #[test]
+    fn unterminated_tags_are_text() {
+        assert_eq!(to_markdown("<p>a</p> <"), "a\n\n <\n");
+        assert_eq!(to_markdown("<"), "<\n");
+        assert_eq!(to_markdown("x <em"), "x <em\n");
+    }
+}
@@ -0,0 +1,574 @@
+use std::{
+    cmp::Reverse,
+    fs::{self, File},
+    io::{self, BufRead},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    cache::{self, AnswerCache, CachedAnswer},
+    core::{Answer, Cancellation, CoreError, Cost, Day, Part, Result, Solver, SolverFn, Year},
+    timing::PhaseTimings,
+};
+
+// Each year is behind a cargo feature of the same name, so builds can leave
+// out the years they don't need; a disabled year simply has no solvers.
+#[cfg(feature = "y2015")]
+use crate::y2015;
+#[cfg(feature = "y2019")]
+use crate::y2019;
+#[cfg(feature = "y2022")]
+use crate::y2022;
+#[cfg(feature = "y2023")]
+use crate::y2023;
+#[cfg(feature = "y2024")]
+use crate::y2024;
+
+pub fn registry(year: &Year) -> &'static [(u16, u16, SolverFn)] {
+    match year.raw_value() {
+        #[cfg(feature = "y2015")]
+        2015 => y2015::SOLVERS,
+        #[cfg(feature = "y2019")]
+        2019 => y2019::SOLVERS,
+        #[cfg(feature = "y2022")]
+        2022 => y2022::SOLVERS,
+        #[cfg(feature = "y2023")]
+        2023 => y2023::SOLVERS,
+        #[cfg(feature = "y2024")]
+        2024 => y2024::SOLVERS,
+        _ => &[],
+    }
+}
+
+// The years with solvers compiled in.
+pub fn years() -> Vec<Year> {
+    Year::all()
+        .filter(|year| !registry(year).is_empty())
+        .collect()
+}
+
+pub fn variants(year: &Year, day: &Day, part: &Part) -> Vec<(&'static str, SolverFn)> {
+    let variants: &[(u16, u16, &str, SolverFn)] = match year.raw_value() {
+        #[cfg(feature = "y2023")]
+        2023 => y2023::VARIANTS,
+        _ => &[],
+    };
+    variants
+        .iter()
+        .filter(|(d, p, _, _)| *d == day.raw_value() && *p == part.raw_value())
+        .map(|(_, _, name, solver)| (*name, *solver))
+        .collect()
+}
+
+type GeneratorFn = fn(&Day, usize, u64) -> Result<Vec<String>>;
+
+// Random inputs from `aoc gen`, for the days with a generator.
+pub fn generate(year: &Year, day: &Day, size: usize, seed: u64) -> Result<Vec<String>> {
+    let generate: Option<GeneratorFn> = match year.raw_value() {
+        #[cfg(feature = "y2023")]
+        2023 => Some(y2023::gen::generate),
+        _ => None,
+    };
+    match generate {
+        Some(generate) => generate(day, size, seed),
+        None => {
+            let msg = format!("No input generator for {} day {}", year, day);
+            Err(CoreError::general(&msg))
+        }
+    }
+}
+
+pub fn get_variant(year: &Year, day: &Day, part: &Part, name: &str) -> Result<Box<dyn Solver>> {
+    let variants = variants(year, day, part);
+    match variants.iter().find(|(n, _)| *n == name) {
+        Some((_, solver)) => Ok(solver()),
+        None => {
+            let names: Vec<&str> = variants.iter().map(|(n, _)| *n).collect();
+            let available = match names.is_empty() {
+                true => "none".to_string(),
+                false => names.join(", "),
+            };
+            Err(CoreError::General(format!(
+                "No algorithm '{}' for {} day {} part {} (available: {})",
+                name, year, day, part, available
+            )))
+        }
+    }
+}
+
+pub fn get_solver(year: &Year, day: &Day, part: &Part) -> Result<Box<dyn Solver>> {
+    #[cfg(feature = "plugins")]
+    if let Some(solver) = crate::plugins::find(year, day, part) {
+        return Ok(solver());
+    }
+    match year.raw_value() {
+        #[cfg(feature = "y2015")]
+        2015 => y2015::get_solver(day, part),
+        #[cfg(feature = "y2019")]
+        2019 => y2019::get_solver(day, part),
+        #[cfg(feature = "y2022")]
+        2022 => y2022::get_solver(day, part),
+        #[cfg(feature = "y2023")]
+        2023 => y2023::get_solver(day, part),
+        #[cfg(feature = "y2024")]
+        2024 => y2024::get_solver(day, part),
+        _ => Err(CoreError::SolverNotImplemented {
+            year: *year,
+            day: *day,
+            part: *part,
+        }),
+    }
+}
+
+pub fn implemented(year: &Year) -> Vec<(Day, Part)> {
+    Day::all()
+        .flat_map(|day| Part::all().map(move |part| (day, part)))
+        .filter(|(day, part)| {
+            registry(year)
+                .iter()
+                .any(|(d, p, _)| *d == day.raw_value() && *p == part.raw_value())
+        })
+        .collect()
+}
+
+pub fn input_path(inputs_dir: &Path, year: &Year, day: &Day) -> PathBuf {
+    let short_filename = format!("{}{}.txt", year, day);
+    inputs_dir.join(short_filename)
+}
+
+pub fn example_path(inputs_dir: &Path, year: &Year, day: &Day, part: &Part) -> PathBuf {
+    let short_filename = format!("{}{}-{}.txt", year, day, part.raw_value());
+    inputs_dir.join("examples").join(short_filename)
+}
+
+pub fn read_lines<P>(filename: P) -> Result<io::Lines<io::BufReader<File>>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(&filename).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => CoreError::MissingInput(filename.as_ref().to_path_buf()),
+        _ => CoreError::from(e),
+    })?;
+    Ok(io::BufReader::new(file).lines())
+}
+
+pub fn solve<I>(solver: &mut dyn Solver, lines: I) -> Result<(Answer, PhaseTimings)>
+where
+    I: IntoIterator<Item = io::Result<String>>,
+{
+    solve_cancellable(solver, lines, &Cancellation::default())
+}
+
+// Stops between lines once `cancellation` is set. The solver gets a copy too,
+// so it can stop part way through `extract_solution` if it checks.
+pub fn solve_cancellable<I>(
+    solver: &mut dyn Solver,
+    lines: I,
+    cancellation: &Cancellation,
+) -> Result<(Answer, PhaseTimings)>
+where
+    I: IntoIterator<Item = io::Result<String>>,
+{
+    solver.set_cancellation(cancellation.clone());
+    let mut timings = PhaseTimings::default();
+
+    if solver.whole_input() {
+        let lines = lines.into_iter().collect::<io::Result<Vec<String>>>()?;
+        let start = Instant::now();
+        solver.handle_input(&lines.join("\n"))?;
+        timings.record_input(lines.len(), start.elapsed());
+    } else {
+        for (i, line) in lines.into_iter().enumerate() {
+            if cancellation.is_cancelled() {
+                return Err(CoreError::Interrupted(format!("{} lines", i)));
+            }
+            let line = line?;
+            let start = Instant::now();
+            solver
+                .handle_line(&line)
+                .map_err(|e| CoreError::parse(i + 1, &line, e))?;
+            timings.record_line(i + 1, start.elapsed());
+        }
+    }
+
+    let start = Instant::now();
+    let solution = solver.extract_solution()?;
+    timings.record_solution(start.elapsed());
+
+    Ok((solution, timings))
+}
+
+// For a solver that has already been given its input, e.g. by `take_parsed`;
+// only the solution phase is timed.
+pub fn solve_parsed(
+    solver: &mut dyn Solver,
+    cancellation: &Cancellation,
+) -> Result<(Answer, PhaseTimings)> {
+    solver.set_cancellation(cancellation.clone());
+    let mut timings = PhaseTimings::default();
+    let start = Instant::now();
+    let solution = solver.extract_solution()?;
+    timings.record_solution(start.elapsed());
+    Ok((solution, timings))
+}
+
+// Feeds every line to the solver without extracting a solution, so malformed
+// input shows up before any real work is done. Returns the number of lines.
+pub fn check<I>(solver: &mut dyn Solver, lines: I) -> Result<usize>
+where
+    I: IntoIterator<Item = io::Result<String>>,
+{
+    if solver.whole_input() {
+        let lines = lines.into_iter().collect::<io::Result<Vec<String>>>()?;
+        solver.handle_input(&lines.join("\n"))?;
+        return Ok(lines.len());
+    }
+
+    let mut count = 0;
+    for (i, line) in lines.into_iter().enumerate() {
+        let line = line?;
+        solver
+            .handle_line(&line)
+            .map_err(|e| CoreError::parse(i + 1, &line, e))?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+pub fn check_day(inputs_dir: &Path, year: &Year, day: &Day, part: &Part) -> Result<usize> {
+    let lines = read_lines(input_path(inputs_dir, year, day))?;
+    let mut solver = get_solver(year, day, part)?;
+    check(solver.as_mut(), lines)
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    MissingInput,
+    Solved(Answer, PhaseTimings),
+    Cached(Answer, Duration),
+    Failed(CoreError),
+}
+
+pub fn solve_day(inputs_dir: &Path, year: &Year, day: &Day, part: &Part) -> Outcome {
+    let path = input_path(inputs_dir, year, day);
+    if !path.exists() {
+        return Outcome::MissingInput;
+    }
+
+    let outcome = read_lines(path).and_then(|lines| {
+        let mut solver = get_solver(year, day, part)?;
+        solve(solver.as_mut(), lines)
+    });
+    match outcome {
+        Ok((answer, timings)) => Outcome::Solved(answer, timings),
+        Err(e) => Outcome::Failed(e),
+    }
+}
+
+pub fn title(year: &Year, day: &Day) -> Option<&'static str> {
+    Part::all().find_map(|part| get_solver(year, day, &part).ok()?.title())
+}
+
+pub fn description(year: &Year, day: &Day) -> Option<&'static str> {
+    Part::all().find_map(|part| get_solver(year, day, &part).ok()?.description())
+}
+
+pub fn cost(year: &Year, day: &Day, part: &Part) -> Cost {
+    get_solver(year, day, part).map_or(Cost::Fast, |solver| solver.cost())
+}
+
+// Solvers run a few at a time, slow ones first so they aren't left running
+// alone at the end; results come back in the same order as `puzzles`.
+pub fn solve_all(
+    inputs_dir: &Path,
+    year: &Year,
+    puzzles: &[(Day, Part)],
+) -> Vec<(Day, Part, Outcome)> {
+    let mut order: Vec<usize> = (0..puzzles.len()).collect();
+    order.sort_by_key(|&i| Reverse(cost(year, &puzzles[i].0, &puzzles[i].1)));
+
+    let mut outcomes: Vec<Option<Outcome>> = puzzles.iter().map(|_| None).collect();
+    for (i, outcome) in solve_in_order(&order, |i| {
+        let (day, part) = puzzles[i];
+        solve_guarded(inputs_dir, year, &day, &part)
+    }) {
+        outcomes[i] = Some(outcome);
+    }
+    puzzles
+        .iter()
+        .zip(outcomes)
+        .map(|(&(day, part), outcome)| {
+            let outcome =
+                outcome.unwrap_or_else(|| Outcome::Failed(CoreError::general("Solver didn't run")));
+            (day, part, outcome)
+        })
+        .collect()
+}
+
+/// Like `solve_day`, but a panicking solver becomes a failed outcome
+/// instead of taking the caller down with it.
+pub fn solve_guarded(inputs_dir: &Path, year: &Year, day: &Day, part: &Part) -> Outcome {
+    panic::catch_unwind(AssertUnwindSafe(|| solve_day(inputs_dir, year, day, part)))
+        .unwrap_or_else(|_| Outcome::Failed(CoreError::general("Solver panicked")))
+}
+
+// Runs on rayon's global pool, which `--threads` sets the size of. Each item
+// is its own task, so the ones at the front of `order` start first.
+#[cfg(feature = "parallel")]
+fn solve_in_order<F>(order: &[usize], solve: F) -> Vec<(usize, Outcome)>
+where
+    F: Fn(usize) -> Outcome + Sync,
+{
+    use rayon::prelude::*;
+
+    order
+        .par_iter()
+        .with_max_len(1)
+        .map(|&i| (i, solve(i)))
+        .collect()
+}
+
+// Without rayon, a worker per core takes the next item from `order` until
+// there are none left.
+#[cfg(not(feature = "parallel"))]
+fn solve_in_order<F>(order: &[usize], solve: F) -> Vec<(usize, Outcome)>
+where
+    F: Fn(usize) -> Outcome + Sync,
+{
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    };
+    use std::thread;
+
+    let workers = thread::available_parallelism().map_or(1, |n| n.get());
+    let next = AtomicUsize::new(0);
+    let solved = Mutex::new(Vec::with_capacity(order.len()));
+    thread::scope(|scope| {
+        for _ in 0..workers.min(order.len()) {
+            scope.spawn(|| {
+                while let Some(&i) = order.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcome = solve(i);
+                    solved.lock().unwrap().push((i, outcome));
+                }
+            });
+        }
+    });
+    solved.into_inner().unwrap()
+}
+
+// Like `solve_all`, but only runs solvers whose input (or the binary itself)
+// changed since their answer was cached.
+pub fn solve_all_cached(
+    inputs_dir: &Path,
+    year: &Year,
+    puzzles: &[(Day, Part)],
+    cache: &mut AnswerCache,
+) -> Vec<(Day, Part, Outcome)> {
+    let binary = cache::binary_fingerprint();
+    let keys: Vec<Option<String>> = puzzles
+        .iter()
+        .map(|(day, part)| {
+            let input = fs::read(input_path(inputs_dir, year, day)).ok()?;
+            Some(cache::key(year, day, part, &input, &binary))
+        })
+        .collect();
+    let hits: Vec<Option<CachedAnswer>> = keys
+        .iter()
+        .map(|key| key.as_ref().and_then(|key| cache.get(key)).cloned())
+        .collect();
+
+    let misses: Vec<(Day, Part)> = puzzles
+        .iter()
+        .zip(&hits)
+        .filter(|(_, hit)| hit.is_none())
+        .map(|(puzzle, _)| *puzzle)
+        .collect();
+    let mut solved = solve_all(inputs_dir, year, &misses).into_iter().peekable();
+
+    let mut outcomes = vec![];
+    for ((&(day, part), key), hit) in puzzles.iter().zip(keys).zip(hits) {
+        let outcome = match (hit, solved.next_if(|(d, p, _)| (*d, *p) == (day, part))) {
+            (Some(hit), _) => Outcome::Cached(hit.answer.clone(), hit.elapsed()),
+            (None, Some((_, _, outcome))) => {
+                if let (Some(key), Outcome::Solved(answer, timings)) = (key, &outcome) {
+                    cache.insert(key, answer, timings.total());
+                }
+                outcome
+            }
+            (None, None) => Outcome::Failed(CoreError::general("Solver didn't run")),
+        };
+        outcomes.push((day, part, outcome));
+    }
+    outcomes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn lists_years_with_solvers() {
+        let years: Vec<u16> = years().iter().map(Year::raw_value).collect();
+        assert_eq!(years, vec![2015, 2019, 2022, 2023]);
+
+        // 2024 is ready for solvers, but doesn't have any yet.
+        let year: Year = "2024".parse().unwrap();
+        let err = get_solver(&year, &"1".parse().unwrap(), &"1".parse().unwrap())
+            .err()
+            .unwrap();
+        assert!(matches!(err, CoreError::SolverNotImplemented { .. }));
+    }
+
+    #[test]
+    fn lists_implemented_days() {
+        let year: Year = "2023".parse().unwrap();
+        let implemented = implemented(&year);
+        assert_eq!(implemented.len(), 26);
+        assert_eq!(implemented[0], ("1".parse().unwrap(), "1".parse().unwrap()));
+        let err = get_solver(&year, &"13".parse().unwrap(), &"1".parse().unwrap())
+            .err()
+            .unwrap();
+        assert!(matches!(err, CoreError::SolverNotImplemented { .. }));
+        assert_eq!(err.to_string(), "No solver for 2023 day 13 part 1");
+    }
+
+    #[test]
+    fn builds_input_paths() {
+        let year: Year = "2023".parse().unwrap();
+        let day: Day = "7".parse().unwrap();
+        assert_eq!(
+            input_path(Path::new("/tmp/aoc"), &year, &day),
+            PathBuf::from("/tmp/aoc/202307.txt")
+        );
+        assert_eq!(
+            example_path(Path::new("/tmp/aoc"), &year, &day, &"2".parse().unwrap()),
+            PathBuf::from("/tmp/aoc/examples/202307-2.txt")
+        );
+    }
+
+    #[test]
+    fn can_select_variants() -> Result<()> {
+        let year: Year = "2023".parse().unwrap();
+        let day: Day = "7".parse().unwrap();
+        let part: Part = "2".parse().unwrap();
+        let names: Vec<&str> = variants(&year, &day, &part)
+            .iter()
+            .map(|(n, _)| *n)
+            .collect();
+        assert_eq!(names, ["counts", "enumerate"]);
+
+        let mut solver = get_variant(&year, &day, &part, "enumerate")?;
+        let lines = ["32T3K 765", "KTJJT 220"];
+        let (answer, _) = solve(solver.as_mut(), lines.map(|l| Ok(l.to_string())))?;
+        assert_eq!(answer, "1205");
+
+        assert!(get_variant(&year, &day, &part, "magic").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn can_solve_lines() -> Result<()> {
+        let year: Year = "2023".parse().unwrap();
+        let mut solver = get_solver(&year, &"9".parse().unwrap(), &"1".parse().unwrap()).unwrap();
+        let lines = ["0 3 6 9 12 15", "1 3 6 10 15 21"];
+        let (answer, timings) = solve(solver.as_mut(), lines.map(|l| Ok(l.to_string())))?;
+        assert_eq!(answer, "46");
+        assert_eq!(timings.num_lines, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_carry_line_numbers() {
+        let year: Year = "2023".parse().unwrap();
+        let mut solver = get_solver(&year, &"9".parse().unwrap(), &"1".parse().unwrap()).unwrap();
+        let lines = ["0 3 6 9 12 15", "1 3 x"];
+        let err = solve(solver.as_mut(), lines.map(|l| Ok(l.to_string()))).unwrap_err();
+        assert!(matches!(err, CoreError::Parse { line: 2, .. }));
+        assert_eq!(err.exit_code(), 3);
+
+        let err = read_lines("/no/such/input.txt").unwrap_err();
+        assert!(matches!(err, CoreError::MissingInput(_)));
+    }
+
+    #[test]
+    fn can_check_input_without_solving() {
+        let year: Year = "2023".parse().unwrap();
+        let mut solver = get_solver(&year, &"9".parse().unwrap(), &"1".parse().unwrap()).unwrap();
+        let lines = ["0 3 6 9 12 15", "1 3 6 10 15 21"];
+        assert_eq!(
+            check(solver.as_mut(), lines.map(|l| Ok(l.to_string()))).unwrap(),
+            2
+        );
+
+        let mut solver = get_solver(&year, &"9".parse().unwrap(), &"1".parse().unwrap()).unwrap();
+        let lines = ["0 3 6 9 12 15", "10 13 16 21 30 45", "1 3 6 1x"];
+        let err = check(solver.as_mut(), lines.map(|l| Ok(l.to_string()))).unwrap_err();
+        assert!(matches!(err, CoreError::Parse { line: 3, .. }));
+    }
+
+    #[test]
+    fn solve_all_keeps_puzzle_order() {
+        let year: Year = "2023".parse().unwrap();
+        let puzzles = implemented(&year);
+        let outcomes = solve_all(Path::new("./inputs"), &year, &puzzles);
+        let order: Vec<(Day, Part)> = outcomes
+            .iter()
+            .map(|(day, part, _)| (*day, *part))
+            .collect();
+        assert_eq!(order, puzzles);
+    }
+
+    #[test]
+    fn cancelled_runs_stop_between_lines() {
+        let mut solver = get_solver(
+            &"2023".parse().unwrap(),
+            &"9".parse().unwrap(),
+            &"1".parse().unwrap(),
+        )
+        .unwrap();
+        let cancellation = Cancellation::default();
+        cancellation.cancel();
+        let lines = ["0 3 6 9 12 15"].iter().map(|l| Ok(l.to_string()));
+        let err = solve_cancellable(solver.as_mut(), lines, &cancellation)
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "Interrupted after 0 lines");
+        assert_eq!(err.exit_code(), 130);
+    }
+
+    #[test]
+    fn solvers_describe_themselves() {
+        let year = "2023".parse().unwrap();
+        let day = "7".parse().unwrap();
+        assert_eq!(title(&year, &day), Some("Camel Cards"));
+        assert_eq!(cost(&year, &day, &"2".parse().unwrap()), Cost::Fast);
+        let brute = get_variant(&year, &"5".parse().unwrap(), &"2".parse().unwrap(), "brute");
+        assert_eq!(brute.unwrap().cost(), Cost::Slow);
+    }
+
+    #[test]
+    fn cached_answers_are_reused() {
+        let dir = env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("202309.txt"), "0 3 6 9 12 15\n").unwrap();
+
+        let year: Year = "2023".parse().unwrap();
+        let puzzles = [("9".parse().unwrap(), "1".parse().unwrap())];
+        let mut cache = AnswerCache::default();
+
+        let outcomes = solve_all_cached(&dir, &year, &puzzles, &mut cache);
+        assert!(matches!(&outcomes[0].2, Outcome::Solved(answer, _) if *answer == "18"));
+        let outcomes = solve_all_cached(&dir, &year, &puzzles, &mut cache);
+        assert!(matches!(&outcomes[0].2, Outcome::Cached(answer, _) if *answer == "18"));
+
+        fs::write(dir.join("202309.txt"), "1 3 6 10 15 21\n").unwrap();
+        let outcomes = solve_all_cached(&dir, &year, &puzzles, &mut cache);
+        assert!(matches!(&outcomes[0].2, Outcome::Solved(answer, _) if *answer == "28"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
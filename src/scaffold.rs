@@ -0,0 +1,139 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    core::{CoreError, Day, Result, Year},
+    runner,
+};
+
+// Where a day's solver lives, e.g. `crates/aoc-y2023/src/d07.rs`.
+pub fn day_source(crates_dir: &Path, year: &Year, day: &Day) -> PathBuf {
+    crates_dir
+        .join(format!("aoc-y{}", year))
+        .join("src")
+        .join(format!("d{}.rs", day))
+}
+
+// Creates `aoc-yYYYY/src/dDD.rs` from a template, returning its path. The
+// year's build.rs picks up the new module and registers it.
+pub fn scaffold(crates_dir: &Path, inputs_dir: &Path, year: &Year, day: &Day) -> Result<PathBuf> {
+    let year_dir = crates_dir.join(format!("aoc-y{}", year)).join("src");
+    let day_rs = day_source(crates_dir, year, day);
+
+    if !year_dir.is_dir() {
+        let msg = format!("{} doesn't exist", year_dir.display());
+        return Err(CoreError::General(msg));
+    }
+    if day_rs.exists() {
+        let msg = format!("{} already exists", day_rs.display());
+        return Err(CoreError::General(msg));
+    }
+
+    // The template's test starts from the part 1 example, if there is one.
+    let example_path = runner::example_path(inputs_dir, year, day, &"1".parse().unwrap());
+    let example = fs::read_to_string(example_path).unwrap_or_default();
+
+    fs::write(&day_rs, day_module(&example))?;
+    Ok(day_rs)
+}
+
+fn day_module(example: &str) -> String {
+    let example = example.trim_end();
+    TEMPLATE
+        .replace("{{HASHES}}", &raw_string_hashes(example))
+        .replace("{{EXAMPLE}}", example)
+}
+
+// Enough `#`s that nothing in `text` can end a raw string early: one more
+// than the longest run following a `"`.
+fn raw_string_hashes(text: &str) -> String {
+    let longest = text
+        .split('"')
+        .skip(1)
+        .map(|rest| rest.chars().take_while(|&c| c == '#').count())
+        .max()
+        .unwrap_or(0);
+    "#".repeat(longest + 1)
+}
+
+const TEMPLATE: &str = r####"use crate::core::{Answer, CoreError, Result, Solver};
+
+pub fn part_1() -> Box<dyn Solver> {
+    Box::new(Builder::default())
+}
+
+pub fn part_2() -> Box<dyn Solver> {
+    Box::new(Builder::default())
+}
+
+#[derive(Debug, Default)]
+struct Builder {
+    lines: Vec<String>,
+}
+
+impl Solver for Builder {
+    fn handle_line(&mut self, line: &str) -> Result<()> {
+        self.lines.push(line.to_string());
+        Ok(())
+    }
+
+    fn extract_solution(&mut self) -> Result<Answer> {
+        Err(CoreError::general("Not solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = r{{HASHES}}"{{EXAMPLE}}"{{HASHES}};
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn can_solve_example() -> Result<()> {
+        let mut solver = part_1();
+        for line in EXAMPLE.lines() {
+            solver.handle_line(line)?;
+        }
+        assert_eq!(solver.extract_solution()?, "");
+        Ok(())
+    }
+}
+"####;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scaffolds_into_existing_years_only() {
+        let crates_dir = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let year: Year = "2023".parse().unwrap();
+        let day: Day = "12".parse().unwrap();
+        assert!(scaffold(&crates_dir, &crates_dir, &year, &day).is_err());
+
+        let year_dir = crates_dir.join("aoc-y2023").join("src");
+        fs::create_dir_all(&year_dir).unwrap();
+        let path = scaffold(&crates_dir, &crates_dir, &year, &day).unwrap();
+        assert_eq!(path, year_dir.join("d12.rs"));
+        assert!(scaffold(&crates_dir, &crates_dir, &year, &day).is_err());
+        fs::remove_dir_all(&crates_dir).unwrap();
+    }
+
+    #[test]
+    fn template_includes_example() {
+        let module = day_module("abc\ndef\n");
+        assert!(module.contains("r#\"abc\ndef\"#"));
+        assert!(module.contains("impl Solver for Builder"));
+    }
+
+    #[test]
+    fn raw_string_outlasts_the_example() {
+        assert_eq!(raw_string_hashes("abc"), "#");
+        assert_eq!(raw_string_hashes("a\"# b\"### c#"), "####");
+        let module = day_module("x = \"#\"");
+        assert!(module.contains("r##\"x = \"#\"\"##"));
+    }
+}
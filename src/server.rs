@@ -0,0 +1,278 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    result,
+    sync::{mpsc, Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use tracing::debug;
+
+use crate::{
+    core::{Cancellation, CoreError, Day, Part, Result, SolutionReport, Year},
+    output::OutputFormat,
+    runner,
+};
+
+#[derive(Debug, PartialEq)]
+struct Request {
+    method: String,
+    path: String,
+    body: String,
+}
+
+#[derive(Debug, PartialEq)]
+struct Response {
+    status: u16,
+    body: String,
+}
+
+// Puzzle inputs are tens of kilobytes, so anything much bigger is a mistake.
+const MAX_BODY: usize = 1 << 20;
+const WORKERS: usize = 8;
+const TIMEOUT: Duration = Duration::from_secs(10);
+// Solvers that check for cancellation give up after this, so one slow or
+// looping solve can't keep a worker for good.
+const SOLVE_TIMEOUT: Duration = Duration::from_secs(30);
+
+// Serves `POST /solve/{year}/{day}/{part}`, with the puzzle input as the
+// request body. Connections are handed to a fixed set of worker threads; once
+// they're all busy and a few more are queued, new connections wait to be
+// accepted.
+pub fn serve(host: &str, port: u16) -> Result<()> {
+    let listener = TcpListener::bind((host, port))?;
+    eprintln!("Listening on http://{}", listener.local_addr()?);
+
+    let (sender, receiver) = mpsc::sync_channel::<TcpStream>(WORKERS);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..WORKERS {
+        let receiver = Arc::clone(&receiver);
+        thread::spawn(move || loop {
+            let stream = match receiver.lock().unwrap().recv() {
+                Ok(stream) => stream,
+                Err(_) => break,
+            };
+            if let Err(e) = handle_connection(stream) {
+                debug!("Connection failed: {}", e);
+            }
+        });
+    }
+
+    for stream in listener.incoming() {
+        if sender.send(stream?).is_err() {
+            return Err(CoreError::general("Server workers stopped"));
+        }
+    }
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream) -> Result<()> {
+    // A client that stops sending shouldn't hold a worker forever.
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+    let response = match read_request(&mut BufReader::new(&stream)) {
+        Ok(request) => handle_guarded(&request),
+        Err(response) => response,
+    };
+    stream.write_all(response.to_http().as_bytes())?;
+    Ok(())
+}
+
+// Anything wrong with the request comes back as the response to send.
+fn read_request<R: BufRead>(reader: &mut R) -> result::Result<Request, Response> {
+    let (method, path, content_length) = read_head(reader).map_err(|e| error_response(400, &e))?;
+    if content_length > MAX_BODY {
+        let msg = format!(
+            "Body is {} bytes, but the limit is {}",
+            content_length, MAX_BODY
+        );
+        return Err(error_response(413, &CoreError::General(msg)));
+    }
+    let body = read_body(reader, content_length).map_err(|e| error_response(400, &e))?;
+    Ok(Request { method, path, body })
+}
+
+// The method, path and content length.
+fn read_head<R: BufRead>(reader: &mut R) -> Result<(String, String, usize)> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(CoreError::general("Malformed request line")),
+    };
+
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        reader.read_line(&mut header)?;
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse()?;
+            }
+        }
+    }
+
+    Ok((method, path, content_length))
+}
+
+fn read_body<R: Read>(reader: &mut R, content_length: usize) -> Result<String> {
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    String::from_utf8(body).map_err(|_| CoreError::general("Body isn't UTF-8"))
+}
+
+// A panicking solver mustn't take its worker down with it.
+fn handle_guarded(request: &Request) -> Response {
+    panic::catch_unwind(AssertUnwindSafe(|| handle(request)))
+        .unwrap_or_else(|_| error_response(500, &CoreError::general("Solver panicked")))
+}
+
+fn handle(request: &Request) -> Response {
+    let segments: Vec<&str> = request.path.trim_matches('/').split('/').collect();
+    let (year, day, part) = match segments.as_slice() {
+        ["solve", year, day, part] => (*year, *day, *part),
+        _ => return error_response(404, &CoreError::general("Not found")),
+    };
+    if request.method != "POST" {
+        return error_response(405, &CoreError::general("Use POST"));
+    }
+
+    match solve(year, day, part, &request.body) {
+        Ok(report) => Response {
+            status: 200,
+            body: serde_json::to_string(&report).unwrap_or_default(),
+        },
+        Err(e) => {
+            let status = match e {
+                CoreError::Argument(_) => 400,
+                CoreError::SolverNotImplemented { .. } => 404,
+                CoreError::Parse { .. } => 422,
+                CoreError::Interrupted(_) => 503,
+                _ => 500,
+            };
+            error_response(status, &e)
+        }
+    }
+}
+
+fn solve(year: &str, day: &str, part: &str, input: &str) -> Result<SolutionReport> {
+    let year: Year = year.parse()?;
+    let day: Day = day.parse()?;
+    let part: Part = part.parse()?;
+    let mut solver = runner::get_solver(&year, &day, &part)?;
+    let lines = input.lines().map(|line| Ok(line.to_string()));
+    let cancellation = Cancellation::after(SOLVE_TIMEOUT);
+    let (answer, timings) = runner::solve_cancellable(solver.as_mut(), lines, &cancellation)?;
+    Ok(SolutionReport::new(
+        &year,
+        &day,
+        &part,
+        answer,
+        timings.total(),
+    ))
+}
+
+fn error_response(status: u16, error: &CoreError) -> Response {
+    Response {
+        status,
+        body: OutputFormat::Json.render_error(error),
+    }
+}
+
+impl Response {
+    fn to_http(&self) -> String {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        };
+        format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.body.len(),
+            self.body
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn post(path: &str, body: &str) -> Request {
+        Request {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn can_read_request() {
+        let raw = "POST /solve/2023/9/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: 13\r\n\r\n0 3 6 9 12 15";
+        let request = read_request(&mut raw.as_bytes()).unwrap();
+        assert_eq!(request, post("/solve/2023/9/1", "0 3 6 9 12 15"));
+    }
+
+    #[test]
+    fn large_and_malformed_requests_are_refused() {
+        let raw = format!(
+            "POST /solve/2023/9/1 HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY + 1
+        );
+        let response = read_request(&mut raw.as_bytes()).unwrap_err();
+        assert_eq!(response.status, 413);
+        assert!(response
+            .to_http()
+            .starts_with("HTTP/1.1 413 Payload Too Large"));
+
+        let raw = "POST /solve/2023/9/1 HTTP/1.1\r\nContent-Length: 20\r\n\r\nshort";
+        assert_eq!(read_request(&mut raw.as_bytes()).unwrap_err().status, 400);
+        assert_eq!(
+            read_request(&mut "nonsense".as_bytes()).unwrap_err().status,
+            400
+        );
+    }
+
+    #[test]
+    fn can_solve_posted_input() {
+        let response = handle(&post("/solve/2023/9/1", "0 3 6 9 12 15\n1 3 6 10 15 21\n"));
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains(r#""answer":"46""#));
+    }
+
+    #[test]
+    fn reports_errors_as_json() {
+        assert_eq!(handle(&post("/other", "")).status, 404);
+        assert_eq!(handle(&post("/solve/2023/26/1", "")).status, 400);
+        assert_eq!(handle(&post("/solve/2023/13/1", "")).status, 404);
+
+        let response = handle(&post("/solve/2023/9/1", "0 3 6\n1 x\n"));
+        assert_eq!(response.status, 422);
+        assert!(response.body.contains(r#""line":2"#));
+
+        let mut request = post("/solve/2023/9/1", "");
+        request.method = "GET".to_string();
+        assert_eq!(handle(&request).status, 405);
+    }
+
+    #[test]
+    fn panicking_solvers_are_server_errors() {
+        let response = handle_guarded(&post("/solve/2023/8/1", "LR\n\nAAA = (BBB, BBB)\n"));
+        assert_eq!(response.status, 500);
+        assert!(response.body.contains("Solver panicked"));
+    }
+}
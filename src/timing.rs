@@ -0,0 +1,163 @@
+use std::{fmt, time::Duration};
+
+#[derive(Debug, Default, Clone)]
+pub struct PhaseTimings {
+    pub handle_line: Duration,
+    pub extract_solution: Duration,
+    pub num_lines: usize,
+    pub slowest_line: Option<(usize, Duration)>,
+}
+
+impl PhaseTimings {
+    pub fn record_line(&mut self, line_number: usize, elapsed: Duration) {
+        self.handle_line += elapsed;
+        self.num_lines += 1;
+        match self.slowest_line {
+            Some((_, slowest)) if slowest >= elapsed => {}
+            _ => self.slowest_line = Some((line_number, elapsed)),
+        }
+    }
+
+    // For solvers that take the whole input at once, so there's no
+    // per-line breakdown.
+    pub fn record_input(&mut self, num_lines: usize, elapsed: Duration) {
+        self.handle_line += elapsed;
+        self.num_lines += num_lines;
+    }
+
+    pub fn record_solution(&mut self, elapsed: Duration) {
+        self.extract_solution += elapsed;
+    }
+
+    pub fn total(&self) -> Duration {
+        self.handle_line + self.extract_solution
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "parse: {:?}\nsolve: {:?}\ntotal: {:?}",
+            self.handle_line,
+            self.extract_solution,
+            self.total()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        let total: Duration = sorted.iter().sum();
+        let mean = total / n as u32;
+
+        let mean_secs = mean.as_secs_f64();
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean_secs).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Some(Self {
+            min: sorted[0],
+            median,
+            mean,
+            max: sorted[n - 1],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "min: {:?}, median: {:?}, max: {:?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "min: {:?}", self.min)?;
+        writeln!(f, "median: {:?}", self.median)?;
+        writeln!(f, "mean: {:?}", self.mean)?;
+        writeln!(f, "max: {:?}", self.max)?;
+        write!(f, "stddev: {:?}", self.stddev)
+    }
+}
+
+impl fmt::Display for PhaseTimings {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "handle_line: {:?} ({} lines)",
+            self.handle_line, self.num_lines
+        )?;
+        if let Some((line_number, elapsed)) = self.slowest_line {
+            writeln!(f, "slowest line: {} ({:?})", line_number, elapsed)?;
+        }
+        writeln!(f, "extract_solution: {:?}", self.extract_solution)?;
+        write!(f, "total: {:?}", self.total())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn slowest_line_is_tracked() {
+        let mut timings = PhaseTimings::default();
+        timings.record_line(1, Duration::from_millis(3));
+        timings.record_line(2, Duration::from_millis(7));
+        timings.record_line(3, Duration::from_millis(5));
+        timings.record_solution(Duration::from_millis(10));
+
+        assert_eq!(timings.num_lines, 3);
+        assert_eq!(timings.slowest_line, Some((2, Duration::from_millis(7))));
+        assert_eq!(timings.handle_line, Duration::from_millis(15));
+        assert_eq!(timings.total(), Duration::from_millis(25));
+    }
+
+    #[test]
+    fn summary_has_parse_and_solve_times() {
+        let mut timings = PhaseTimings::default();
+        timings.record_line(1, Duration::from_millis(2));
+        timings.record_solution(Duration::from_millis(5));
+        assert_eq!(timings.summary(), "parse: 2ms\nsolve: 5ms\ntotal: 7ms");
+    }
+
+    #[test]
+    fn can_calculate_statistics() {
+        let samples: Vec<Duration> = [4, 2, 8, 6].map(Duration::from_millis).to_vec();
+        let stats = Statistics::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.max, Duration::from_millis(8));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+
+        assert_eq!(stats.summary(), "min: 2ms, median: 5ms, max: 8ms");
+
+        assert!(Statistics::from_samples(&[]).is_none());
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame, Terminal,
+};
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::{
+    core::{Day, Part, Result, Year},
+    runner::{self, Outcome},
+};
+
+const MAX_LOG_LINES: usize = 500;
+
+// Collects log output so it can be shown in a panel instead of being written
+// over the UI.
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer(Arc<Mutex<LogLines>>);
+
+#[derive(Debug, Default)]
+struct LogLines {
+    lines: Vec<String>,
+    partial: String,
+}
+
+impl LogBuffer {
+    pub fn lines(&self) -> Vec<String> {
+        self.0
+            .lock()
+            .map(|log| log.lines.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Ok(mut log) = self.0.lock() {
+            log.partial.push_str(&String::from_utf8_lossy(buf));
+            while let Some(i) = log.partial.find('\n') {
+                let line = log.partial[..i].to_string();
+                log.partial.drain(..=i);
+                log.lines.push(line);
+            }
+            let excess = log.lines.len().saturating_sub(MAX_LOG_LINES);
+            log.lines.drain(..excess);
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBuffer {
+    type Writer = LogBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+struct App {
+    year: Year,
+    inputs_dir: PathBuf,
+    puzzles: Vec<(Day, Part)>,
+    results: Vec<Option<String>>,
+    state: ListState,
+    log: LogBuffer,
+}
+
+impl App {
+    fn new(year: Year, inputs_dir: PathBuf, log: LogBuffer) -> Self {
+        let puzzles = runner::implemented(&year);
+        let results = puzzles.iter().map(|_| None).collect();
+        let mut state = ListState::default();
+        if !puzzles.is_empty() {
+            state.select(Some(0));
+        }
+        Self {
+            year,
+            inputs_dir,
+            puzzles,
+            results,
+            state,
+            log,
+        }
+    }
+
+    fn next(&mut self) {
+        self.move_selection(1);
+    }
+
+    fn previous(&mut self) {
+        self.move_selection(self.puzzles.len().saturating_sub(1));
+    }
+
+    fn move_selection(&mut self, by: usize) {
+        if let Some(i) = self.state.selected() {
+            self.state.select(Some((i + by) % self.puzzles.len()));
+        }
+    }
+
+    fn run_selected(&mut self) {
+        if let Some(i) = self.state.selected() {
+            let (day, part) = self.puzzles[i];
+            let outcome = runner::solve_guarded(&self.inputs_dir, &self.year, &day, &part);
+            self.record(i, &outcome);
+        }
+    }
+
+    fn run_all(&mut self) {
+        let outcomes = runner::solve_all(&self.inputs_dir, &self.year, &self.puzzles);
+        for (i, (_, _, outcome)) in outcomes.iter().enumerate() {
+            self.record(i, outcome);
+        }
+    }
+
+    fn record(&mut self, i: usize, outcome: &Outcome) {
+        let (day, part) = self.puzzles[i];
+        let result = match outcome {
+            Outcome::MissingInput => "missing input".to_string(),
+            Outcome::Solved(answer, timings) => format!("{}  ({:?})", answer, timings.total()),
+            Outcome::Cached(answer, elapsed) => format!("{}  ({:?}, cached)", answer, elapsed),
+            Outcome::Failed(e) => format!("error: {}", e),
+        };
+        tracing::info!("{} day {} part {}: {}", self.year, day, part, result);
+        self.results[i] = Some(result);
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(frame.size());
+
+        let items: Vec<ListItem> = self
+            .puzzles
+            .iter()
+            .zip(&self.results)
+            .map(|((day, part), result)| {
+                let result = result.as_deref().unwrap_or("-");
+                ListItem::new(format!(
+                    "{:>3}  {:>4}  {}",
+                    day.raw_value(),
+                    part.raw_value(),
+                    result
+                ))
+            })
+            .collect();
+        let title = format!(" {}  (enter: run, a: run all, q: quit) ", self.year);
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, columns[0], &mut self.state);
+
+        let height = columns[1].height.saturating_sub(2) as usize;
+        let lines = self.log.lines();
+        let tail = lines[lines.len().saturating_sub(height)..].join("\n");
+        let log = Paragraph::new(tail).block(Block::default().borders(Borders::ALL).title(" Log "));
+        frame.render_widget(log, columns[1]);
+    }
+}
+
+pub fn run(year: Year, inputs_dir: PathBuf, log: LogBuffer) -> Result<()> {
+    terminal::enable_raw_mode()?;
+    let _restore = RestoreTerminal;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let mut app = App::new(year, inputs_dir, log);
+    event_loop(&mut terminal, &mut app)
+}
+
+// Gives the terminal back when `run` returns, fails part way or panics.
+struct RestoreTerminal;
+
+impl Drop for RestoreTerminal {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, cursor::Show);
+    }
+}
+
+fn event_loop(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App) -> Result<()> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => app.next(),
+                KeyCode::Up | KeyCode::Char('k') => app.previous(),
+                KeyCode::Enter => app.run_selected(),
+                KeyCode::Char('a') => app.run_all(),
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn selection_wraps_around() {
+        let year: Year = "2023".parse().unwrap();
+        let mut app = App::new(year, PathBuf::from("./inputs"), LogBuffer::default());
+        assert_eq!(app.state.selected(), Some(0));
+        app.previous();
+        assert_eq!(app.state.selected(), Some(app.puzzles.len() - 1));
+        app.next();
+        app.next();
+        assert_eq!(app.state.selected(), Some(1));
+    }
+
+    #[test]
+    fn log_buffer_splits_lines() {
+        let mut log = LogBuffer::default();
+        write!(log, "first\nsec").unwrap();
+        writeln!(log, "ond").unwrap();
+        assert_eq!(log.lines(), vec!["first", "second"]);
+    }
+}
@@ -0,0 +1,61 @@
+use std::{fs, path::PathBuf, time::SystemTime};
+
+// Polls modification times rather than relying on OS notifications; a
+// missing file counts as a distinct state so creating it is a change too.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    last_modified: Vec<Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let last_modified = paths.iter().map(|_| None).collect();
+        Self {
+            paths,
+            last_modified,
+        }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    // Returns true if anything changed since the previous call (and always on
+    // the first call for files that exist).
+    pub fn changed(&mut self) -> bool {
+        let current: Vec<Option<SystemTime>> = self.paths.iter().map(modified).collect();
+        let changed = current != self.last_modified;
+        self.last_modified = current;
+        changed
+    }
+}
+
+fn modified(path: &PathBuf) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::{env, thread, time::Duration};
+
+    #[test]
+    fn notices_modifications() {
+        let path = env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        let mut watcher = Watcher::new(vec![path.clone()]);
+        assert!(!watcher.changed());
+
+        fs::write(&path, "1").unwrap();
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        thread::sleep(Duration::from_millis(20));
+        fs::write(&path, "2").unwrap();
+        assert!(watcher.changed());
+
+        fs::remove_file(&path).unwrap();
+        assert!(watcher.changed());
+    }
+}